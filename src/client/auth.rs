@@ -3,11 +3,14 @@ use serde_json::json;
 use super::*;
 use crate::client::ws::WsClientBuilder;
 use crate::error::AuthError;
+use crate::types::auction::{Auction, AuctionPayload};
 use crate::types::http::APIV1Result;
-use crate::types::request::OrderCreationRequest;
+use crate::types::request::{OrderCreationRequest, RivenAuctionRequest};
 use crate::types::request::OrderUpdateParams;
 use crate::types::transaction::Transaction;
 use std::collections::HashMap;
+use std::num::NonZeroU32;
+use std::time::Duration;
 
 impl Client<Unauthenticated> {
     /**
@@ -18,19 +21,192 @@ impl Client<Unauthenticated> {
     */
     pub fn new() -> Self {
         Client {
-            http: build_http(None),
+            http: build_http(&HttpConfig::default()),
+            transport: None,
             user: None,
             orders: Vec::new(),
             status: StatusType::Offline,
-            items_cache: Vec::new(),
-            rivens_cache: Vec::new(),
-            token: None,
+            items_cache: RwLock::new(
+                Cache::new(DEFAULT_CACHE_TTL)
+                    .with_index(ID_INDEX, |item: &Item| Some(item.get_id()))
+                    .with_index(GAME_REF_INDEX, Item::game_ref)
+                    .with_index(SLUG_INDEX, |item: &Item| Some(item.get_slug())),
+            ),
+            rivens_cache: RwLock::new(Cache::new(DEFAULT_CACHE_TTL)),
+            token: RwLock::new(None),
             device_id: None,
+            credentials: None,
+            http_config: HttpConfig::default(),
             limiter: build_limiter(REQUESTS_PER_SECOND).into(),
+            max_retries: DEFAULT_MAX_RETRIES,
+            etags: None,
+            batch_options: BatchOptions::default(),
             _state: PhantomData,
         }
     }
 
+    /**
+    Set how many requests per second the client is allowed to send
+
+    # Note
+    warframe.market publishes a limit of 3 requests per second, going above it
+    without having a higher limit negotiated may get you rate limited or banned
+
+    # Arguments
+    - `rps`: Maximum number of requests per second (default: 3)
+
+    # Returns
+    The client with the rate limit applied
+    */
+    pub fn with_rate_limit(mut self, rps: NonZeroU32) -> Self {
+        self.limiter = build_limiter(rps).into();
+        self
+    }
+
+    /**
+    Set how long a request may take in total before failing
+
+    # Arguments
+    - `timeout`: Time until a request is aborted (default: 30 seconds)
+
+    # Returns
+    The client with the timeout applied
+    */
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.timeout = timeout;
+        self.http = build_http(&self.http_config);
+        self
+    }
+
+    /**
+    Set how long establishing a connection may take before failing
+
+    # Arguments
+    - `timeout`: Time until connecting is aborted (default: 10 seconds)
+
+    # Returns
+    The client with the connect timeout applied
+    */
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.connect_timeout = timeout;
+        self.http = build_http(&self.http_config);
+        self
+    }
+
+    /**
+    Route all requests through a proxy
+
+    # Arguments
+    - `proxy`: The proxy to use, e.g. `reqwest::Proxy::all("http://127.0.0.1:8080")`
+
+    # Returns
+    The client with the proxy applied
+    */
+    pub fn with_proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.http_config.proxy = Some(proxy);
+        self.http = build_http(&self.http_config);
+        self
+    }
+
+    /**
+    Use an existing HTTP client, e.g. to share its connection pool or TLS setup
+
+    # Note
+    The headers WFM requires are added to every request, so the client needs no special setup.
+    Calling [`with_timeout`][Client::with_timeout], [`with_connect_timeout`][Client::with_connect_timeout]
+    or [`with_proxy`][Client::with_proxy] afterwards replaces it with a client built by this crate
+
+    # Arguments
+    - `client`: The `reqwest::Client` to send requests with
+
+    # Returns
+    The client using the given HTTP client
+    */
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.http = client;
+        self
+    }
+
+    /**
+    Send every request through a custom [`HttpTransport`][crate::client::HttpTransport],
+    e.g. to answer requests with canned responses in tests
+
+    # Note
+    Takes precedence over the HTTP client, timeouts and proxies set on the client no longer apply
+
+    # Arguments
+    - `transport`: The transport to send requests with
+
+    # Returns
+    The client using the given transport
+    */
+    pub fn with_transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /**
+    Set how many times idempotent (GET) requests are retried on connection errors
+    or rate limiting (HTTP 429/503) responses, waiting with an exponential backoff in between
+
+    # Arguments
+    - `max`: Maximum number of retries, `0` disables retrying (default: 3)
+
+    # Returns
+    The client with the retry policy applied
+    */
+    pub fn with_retries(mut self, max: u32) -> Self {
+        self.max_retries = max;
+        self
+    }
+
+    /**
+    Revalidate GET responses through their `ETag` instead of downloading them again,
+    a `304 Not Modified` response is answered with the previously received body
+
+    # Note
+    Every response carrying an `ETag` is kept in memory for the lifetime of the client
+
+    # Arguments
+    - `enabled`: Whether to send `If-None-Match` on repeated requests (default: false)
+
+    # Returns
+    The client with ETag caching applied
+    */
+    pub fn with_etag_cache(mut self, enabled: bool) -> Self {
+        self.etags = enabled.then(|| RwLock::new(ETagCache::default()));
+        self
+    }
+
+    /**
+    Set how batch methods fan out their requests
+
+    # Arguments
+    - `options`: The [`BatchOptions`][crate::client::BatchOptions] shared by every batch method
+
+    # Returns
+    The client with the batch options applied
+    */
+    pub fn with_batch_options(mut self, options: BatchOptions) -> Self {
+        self.batch_options = options;
+        self
+    }
+
+    /**
+    Set how long the item and riven lists are cached before being fetched again
+
+    # Arguments
+    - `ttl`: Time until the cached lists expire (default: 1 hour)
+
+    # Returns
+    The client with the cache TTL applied
+    */
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.items_cache.get_mut().unwrap().set_ttl(ttl);
+        self.rivens_cache.get_mut().unwrap().set_ttl(ttl);
+        self
+    }
+
     /**
     Log in using username and password
 
@@ -40,7 +216,9 @@ impl Client<Unauthenticated> {
     - `device_id`: Unique identifier across the device, should not change between instances
 
     # Returns
-    An authenticated client
+    - An authenticated client
+    - `AuthError::TwoFactorRequired` if the account has two-factor authentication enabled,
+      see [`login_with_2fa`][Client::login_with_2fa]
     */
     pub async fn login(
         self,
@@ -48,65 +226,187 @@ impl Client<Unauthenticated> {
         password: &str,
         device_id: &str,
     ) -> Result<Client<Authenticated>, AuthError> {
-        let mut map = HashMap::new();
-        map.insert("auth_type", "header");
-        map.insert("email", username);
-        map.insert("password", password);
-        map.insert("device_id", device_id);
+        self.authenticate(username, password, device_id, None).await
+    }
+
+    /**
+    Log in to an account with two-factor authentication enabled
 
-        match self
-            .http
-            .post(V1_API.to_owned() + "/auth/signin")
+    # Arguments
+    - `username`: Users account username
+    - `password`: Users account password
+    - `device_id`: Unique identifier across the device, should not change between instances
+    - `code`: The current two-factor (or emailed) verification code
+
+    # Returns
+    An authenticated client
+    */
+    pub async fn login_with_2fa(
+        self,
+        username: &str,
+        password: &str,
+        device_id: &str,
+        code: &str,
+    ) -> Result<Client<Authenticated>, AuthError> {
+        self.authenticate(username, password, device_id, Some(code)).await
+    }
+
+    async fn authenticate(
+        self,
+        username: &str,
+        password: &str,
+        device_id: &str,
+        code: Option<&str>,
+    ) -> Result<Client<Authenticated>, AuthError> {
+        let (jwt, user) = self.sign_in(username, password, device_id, code).await?;
+
+        let mut authed_client = Client {
+            http: self.http,
+            transport: self.transport,
+            user: Some(user.clone()),
+            orders: Vec::new(),
+            status: user.status_type,
+            items_cache: self.items_cache,
+            rivens_cache: self.rivens_cache,
+            token: RwLock::new(Some(jwt)),
+            device_id: Some(device_id.to_string()),
+            credentials: None,
+            http_config: self.http_config,
+            limiter: self.limiter,
+            max_retries: self.max_retries,
+            etags: self.etags,
+            batch_options: self.batch_options,
+            _state: PhantomData,
+        };
+
+        authed_client.refresh().await.map_err(|_| {
+            AuthError::Unknown(
+                "Unable to refresh user after authentication".to_string(),
+            )
+        })?;
+
+        Ok(authed_client)
+    }
+
+    /**
+    Resume a session using a token saved from a previous login,
+    see [`get_token`][Client::get_token] and [`get_device_id`][Client::get_device_id]
+
+    # Arguments
+    - `token`: The users JWT token
+    - `device_id`: The device id the token was issued to
+
+    # Returns
+    - An authenticated client
+    - `AuthError::TokenExpired` if the token is invalid or expired, log in again in that case
+    - `AuthError::Api` if the session could not be checked, e.g. due to a network error
+    */
+    pub async fn login_with_token(
+        self,
+        token: &str,
+        device_id: &str,
+    ) -> Result<Client<Authenticated>, AuthError> {
+        let mut authed_client = Client {
+            http: self.http,
+            transport: self.transport,
+            user: None,
+            orders: Vec::new(),
+            status: StatusType::Offline,
+            items_cache: self.items_cache,
+            rivens_cache: self.rivens_cache,
+            token: RwLock::new(Some(token.to_string())),
+            device_id: Some(device_id.to_string()),
+            credentials: None,
+            http_config: self.http_config,
+            limiter: self.limiter,
+            max_retries: self.max_retries,
+            etags: self.etags,
+            batch_options: self.batch_options,
+            _state: PhantomData,
+        };
+
+        let user = authed_client.refresh().await?;
+        authed_client.status = user.status_type;
+
+        Ok(authed_client)
+    }
+}
+
+impl<State> Client<State> {
+    /**
+    INTERNAL: Sign in against the v1 API
+
+    # Returns
+    The JWT token (without the "JWT " prefix) and the signed in user
+    */
+    pub(super) async fn sign_in(
+        &self,
+        username: &str,
+        password: &str,
+        device_id: &str,
+        code: Option<&str>,
+    ) -> Result<(String, FullUser), AuthError> {
+        let mut map = self.build_auth_payload(username, password, device_id);
+        if let Some(code) = code {
+            map.insert("code", code);
+        }
+
+        let request = with_headers(self.http.post(V1_API.to_owned() + "/auth/signin"), None)
             .json(&map)
             .header("Authorization", "JWT")
-            .send()
-            .await
-        {
+            .build()
+            .map_err(|e| AuthError::Unknown(format!("Unable to build request: {}", e)))?;
+
+        match self.send_request(request).await {
             Ok(resp) => {
-                let headers = resp.headers().clone();
-                let body = resp.text().await.unwrap();
+                let status = resp.status;
+                let headers = resp.headers;
+                let body = resp.body;
+
+                if !status.is_success() {
+                    return Err(AuthError::from_response(status.as_u16(), &body));
+                }
 
                 let data: APIV1Result<AuthResp> =
                     serde_json::from_str(&body).map_err(|_| AuthError::ParsingError)?;
+                let user = data.payload.user.ok_or(AuthError::NoUser)?;
 
-                match headers.get("Authorization") {
-                    Some(header) => {
-                        let token: String = header
-                            .to_str()
-                            .map_err(|_| AuthError::ParsingError)?
-                            .to_string();
-
-                        let jwt = &token[4..]; // Remove the "JWT " from the token.
-                        let http = build_http(Some(format!("Bearer {}", jwt)));
-
-                        let mut authed_client = Client {
-                            http,
-                            user: Some(data.payload.user.clone()),
-                            orders: Vec::new(),
-                            status: data.payload.user.status_type,
-                            items_cache: self.items_cache,
-                            rivens_cache: self.rivens_cache,
-                            token: Some(jwt.to_string()),
-                            device_id: Some(device_id.parse().unwrap()),
-                            limiter: build_limiter(REQUESTS_PER_SECOND).into(),
-                            _state: PhantomData,
-                        };
-
-                        authed_client.refresh().await.map_err(|_| {
-                            AuthError::Unknown(
-                                "Unable to refresh user after authentication".to_string(),
-                            )
-                        })?;
-
-                        Ok(authed_client)
-                    }
-                    None => Err(AuthError::ParsingError),
-                }
+                // Remove the "JWT " from the token.
+                let jwt = headers
+                    .get("Authorization")
+                    .and_then(|header| header.to_str().ok())
+                    .and_then(|token| token.strip_prefix("JWT "))
+                    .ok_or(AuthError::NoToken)?;
+
+                Ok((jwt.to_string(), user))
             }
             Err(e) => Err(AuthError::Unknown(format!("Unknown Error: {:?}", e))),
         }
     }
 
+    /**
+    INTERNAL: Sign in again with the credentials given to `with_auto_reauth`, replacing the token
+
+    # Returns
+    Whether a new token was obtained
+    */
+    pub(super) async fn reauthenticate(&self) -> bool {
+        let (Some((username, password)), Some(device_id)) = (&self.credentials, &self.device_id) else {
+            return false;
+        };
+
+        match self.sign_in(username, password, device_id, None).await {
+            Ok((jwt, _)) => {
+                *self.token.write().unwrap() = Some(jwt);
+                true
+            }
+            Err(e) => {
+                log::warn!("Unable to sign in again after the token expired: {}", e);
+                false
+            }
+        }
+    }
+
     fn build_auth_payload<'a>(
         &self,
         username: &'a str,
@@ -122,6 +422,12 @@ impl Client<Unauthenticated> {
     }
 }
 
+impl Default for Client<Unauthenticated> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Client<Authenticated> {
     /**
     Refresh the users data, updates the state of `orders` and `user`
@@ -129,7 +435,7 @@ impl Client<Authenticated> {
     # Returns
     - A FullUser object
     */
-    pub async fn refresh<'a>(&mut self) -> Result<FullUser, ApiError> {
+    pub async fn refresh(&mut self) -> Result<FullUser, ApiError> {
         let user: Result<ApiResult<FullUser>, ApiError> =
             self.call_api(Method::Get, "/me", None::<&NoBody>).await;
         let orders: Result<ApiResult<Vec<OrderItem>>, ApiError> = self
@@ -139,7 +445,7 @@ impl Client<Authenticated> {
         let order_instances = orders?
             .data
             .iter()
-            .map(|order| Order::new_owned(order))
+            .map(Order::new_owned)
             .collect();
         let user_data = user?.data;
 
@@ -149,6 +455,18 @@ impl Client<Authenticated> {
         Ok(user_data)
     }
 
+    /**
+    Refresh only the users orders, unlike `.refresh()` the user profile is not fetched
+
+    # Returns
+    - The updated `orders`
+    */
+    pub async fn refresh_orders(&mut self) -> Result<&[Order<Owned>], ApiError> {
+        self.orders = self.my_orders().await?;
+
+        Ok(&self.orders)
+    }
+
     /**
     Get the authenticated users orders
 
@@ -163,10 +481,27 @@ impl Client<Authenticated> {
         Ok(items?
             .data
             .iter()
-            .map(|order| Order::new_owned(order))
+            .map(Order::new_owned)
             .collect())
     }
 
+    /**
+    Get the authenticated users completed trades
+
+    # Returns
+    List of all transactions, newest first
+    */
+    pub async fn get_transactions(&self) -> Result<Vec<Transaction>, ApiError> {
+        let transactions: Result<ApiResult<Vec<Transaction>>, ApiError> = self
+            .call_api(Method::Get, "/me/transactions", None::<&NoBody>)
+            .await;
+
+        let mut transactions = transactions?.data;
+        transactions.sort_by_key(|transaction| std::cmp::Reverse(transaction.created_at));
+
+        Ok(transactions)
+    }
+
     /**
     Take ownership of an order, converts an `<Unowned>` order to an `<Owned>` one
 
@@ -201,7 +536,7 @@ impl Client<Authenticated> {
     */
     pub fn get_token(&self) -> String {
         // Only accessible on authed clients, if this panics we got hit by a cosmic particle
-        self.token.clone().unwrap()
+        self.token.read().unwrap().clone().unwrap()
     }
 
     /**
@@ -215,6 +550,79 @@ impl Client<Authenticated> {
         self.device_id.clone().unwrap()
     }
 
+    /**
+    Return the logged in user, as fetched on login or the last [`refresh`][Client::refresh]
+
+    # Returns
+    The users profile
+    */
+    pub fn current_user(&self) -> &FullUser {
+        // Set on every path to an authed client, cosmic particle territory once more
+        self.user.as_ref().unwrap()
+    }
+
+    /**
+    Return the reputation of the logged in user, see [`current_user`][Client::current_user]
+
+    # Returns
+    The users reputation as of the last refresh
+    */
+    pub fn reputation(&self) -> i32 {
+        self.current_user().reputation
+    }
+
+    /**
+    Sign in again automatically once the token expires, retrying the rejected request once
+
+    # Note
+    The credentials are kept in memory for as long as the client lives
+
+    # Arguments
+    - `username`: Users account username
+    - `password`: Users account password
+
+    # Returns
+    The client with automatic re-authentication enabled
+    */
+    pub fn with_auto_reauth(mut self, username: &str, password: &str) -> Self {
+        self.credentials = Some((username.to_string(), password.to_string()));
+        self
+    }
+
+    /**
+    Sign out, invalidating the token on WFM's side
+
+    # Note
+    The item and riven caches and the client configuration are kept
+
+    # Returns
+    An unauthenticated client, e.g. to log in with another account
+    */
+    pub async fn logout(self) -> Result<Client<Unauthenticated>, ApiError> {
+        let _: serde_json::Value = self
+            .call_api_v1(Method::Get, "/auth/signout", None::<&NoBody>)
+            .await?;
+
+        Ok(Client {
+            http: self.http,
+            transport: self.transport,
+            user: None,
+            orders: Vec::new(),
+            status: StatusType::Offline,
+            items_cache: self.items_cache,
+            rivens_cache: self.rivens_cache,
+            token: RwLock::new(None),
+            device_id: None,
+            credentials: None,
+            http_config: self.http_config,
+            limiter: self.limiter,
+            max_retries: self.max_retries,
+            etags: self.etags,
+            batch_options: self.batch_options,
+            _state: PhantomData,
+        })
+    }
+
     /**
     Create a WebSocket builder
 
@@ -232,13 +640,14 @@ impl Client<Authenticated> {
     - `order`: The [`Order`][crate::client::order::Order] to update
 
     # Example
-    ```rust
+    ```rust,no_run
     use wf_market::{
         client::Client,
         utils::generate_device_id,
         types::request::OrderUpdateParams,
     };
 
+    #[tokio::main]
     async fn main() {
         let mut client = {
             // device_id should be stored and reused
@@ -249,23 +658,28 @@ impl Client<Authenticated> {
 
         if let Ok(orders) = client.my_orders().await {
             for order in orders {
-                client.update_order(order, OrderUpdateParams {
-                    platinum: Some(1), // Make all our orders basically free!
-                    ..Default::default()
-                })
+                // Make all our orders basically free!
+                client.update_order(order, OrderUpdateParams::new().with_platinum(1)).await.unwrap();
             }
         }
     }
     ```
 
     # Returns
-    The updated order
+    - The updated order
+    - The order as given, without sending a request, if `args` contains no changes
+      (e.g. an empty [`Order::diff`][crate::client::order::Order::diff])
     */
     pub async fn update_order(
         &self,
         order: Order<Owned>,
         args: OrderUpdateParams,
     ) -> Result<Order<Owned>, ApiError> {
+        // WFM rejects updates without changes, there is nothing to do anyway
+        if args.is_empty() {
+            return Ok(order);
+        }
+
         let order: Result<ApiResult<OrderItem>, ApiError> = self
             .call_api(
                 Method::Patch,
@@ -277,6 +691,31 @@ impl Client<Authenticated> {
         Ok(Order::new_owned(&order?.data))
     }
 
+    /**
+    Update multiple orders concurrently, sharing the clients rate limiter
+
+    # Note
+    Runs according to the clients [`BatchOptions`][crate::client::BatchOptions], with
+    `BatchOrdering::AsCompleted` the results are no longer in the same order as `updates`
+
+    # Arguments
+    - `updates`: Pairs of the [`Order`][crate::client::order::Order] to update and the changes to make
+
+    # Returns
+    The result of every update, a failed update does not abort the others
+    */
+    pub async fn update_orders(
+        &self,
+        updates: Vec<(Order<Owned>, OrderUpdateParams)>,
+    ) -> Vec<Result<Order<Owned>, ApiError>> {
+        let tasks: Vec<_> = updates
+            .into_iter()
+            .map(|(order, args)| self.update_order(order, args))
+            .collect();
+
+        run_batch(self.batch_options, tasks).await
+    }
+
     /**
      * Create a new order
      * # Arguments
@@ -290,6 +729,23 @@ impl Client<Authenticated> {
 
         Ok(Order::new_owned(&order?.data))
     }
+    /**
+    List a riven mod for auction
+
+    # Arguments
+    - `req`: The riven and its pricing, see [`RivenAuctionRequest`]
+
+    # Returns
+    The created [`Auction`]
+    */
+    pub async fn create_riven_auction(&self, req: RivenAuctionRequest) -> Result<Auction, ApiError> {
+        let auction: Result<APIV1Result<AuctionPayload>, ApiError> = self
+            .call_api_v1(Method::Post, "/auctions/create", Some(&req))
+            .await;
+
+        Ok(auction?.payload.auction)
+    }
+
     /**
     Close a portion or all of an existing order.
     Allows you to close part of an open order by specifying a quantity to reduce.
@@ -302,7 +758,6 @@ impl Client<Authenticated> {
     - `Ok(Transaction)` if the order was successfully deleted
     - `Err(ApiError)` if there was an error deleting the order
     */
-
     pub async fn close_order(
         &self,
         order_id: &str,
@@ -321,6 +776,28 @@ impl Client<Authenticated> {
         Ok(transaction?.data)
     }
 
+    /**
+    Close multiple orders concurrently, sharing the clients rate limiter
+
+    # Note
+    Runs according to the clients [`BatchOptions`][crate::client::BatchOptions], with
+    `BatchOrdering::AsCompleted` the results are no longer in the same order as `closes`
+
+    # Arguments
+    - `closes`: Pairs of the ID of the order to close and the quantity to close
+
+    # Returns
+    The result of every close, a failed close does not abort the others
+    */
+    pub async fn close_orders(&self, closes: Vec<(String, u32)>) -> Vec<Result<Transaction, ApiError>> {
+        let tasks: Vec<_> = closes
+            .iter()
+            .map(|(order_id, quantity)| self.close_order(order_id, *quantity))
+            .collect();
+
+        run_batch(self.batch_options, tasks).await
+    }
+
     /**
      * Delete an order
      * # Arguments
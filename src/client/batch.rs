@@ -0,0 +1,53 @@
+use futures_util::StreamExt;
+use futures_util::stream;
+use std::future::Future;
+
+/**
+How batch methods (e.g. [`enrich_orders`][crate::Client::enrich_orders]) fan out their requests
+
+Requests are still throttled by the clients rate limiter, `max_in_flight` only limits
+how many are queued at once.
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BatchOptions {
+    /// Maximum amount of requests running at the same time, `0` is treated as `1`
+    pub max_in_flight: usize,
+    /// Order in which results are returned
+    pub ordering: BatchOrdering,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatchOrdering {
+    /// Results are returned in the same order as the input
+    PreserveInput,
+    /// Results are returned as soon as they complete, trading ordering for latency
+    AsCompleted,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        BatchOptions {
+            max_in_flight: 3,
+            ordering: BatchOrdering::PreserveInput,
+        }
+    }
+}
+
+/**
+INTERNAL: Run a batch of futures according to the batch options
+*/
+pub(super) async fn run_batch<T, F>(
+    options: BatchOptions,
+    tasks: impl IntoIterator<Item = F>,
+) -> Vec<T>
+where
+    F: Future<Output = T>,
+{
+    let tasks = stream::iter(tasks);
+    let max_in_flight = options.max_in_flight.max(1);
+
+    match options.ordering {
+        BatchOrdering::PreserveInput => tasks.buffered(max_in_flight).collect().await,
+        BatchOrdering::AsCompleted => tasks.buffer_unordered(max_in_flight).collect().await,
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+type IndexKey<T> = fn(&T) -> Option<String>;
+
+/**
+INTERNAL: A list of cached API objects which expires after a set amount of time,
+optionally indexed by one or more keys for constant time lookups
+*/
+pub(super) struct Cache<T> {
+    entries: Vec<T>,
+    fetched_at: Option<Instant>,
+    ttl: Duration,
+    indexes: Vec<(&'static str, IndexKey<T>, HashMap<String, usize>)>,
+}
+
+impl<T: Clone> Cache<T> {
+    pub(super) fn new(ttl: Duration) -> Self {
+        Cache {
+            entries: Vec::new(),
+            fetched_at: None,
+            ttl,
+            indexes: Vec::new(),
+        }
+    }
+
+    /**
+    INTERNAL: Index the entries by a key, rebuilt every time the cache is populated
+
+    # Arguments
+    - `name`: Name used to look up entries through `find`
+    - `key`: Extracts the key of an entry, entries without a key are not indexed
+    */
+    pub(super) fn with_index(mut self, name: &'static str, key: IndexKey<T>) -> Self {
+        self.indexes.push((name, key, HashMap::new()));
+        self
+    }
+
+    /**
+    INTERNAL: Whether the cache has been populated and has not yet expired
+    */
+    pub(super) fn is_fresh(&self) -> bool {
+        match self.fetched_at {
+            Some(fetched_at) => fetched_at.elapsed() < self.ttl,
+            None => false,
+        }
+    }
+
+    /**
+    INTERNAL: Get a copy of the cached entries
+
+    # Returns
+    - `None` if the cache is empty or expired
+    */
+    pub(super) fn get(&self) -> Option<Vec<T>> {
+        if self.is_fresh() {
+            Some(self.entries.clone())
+        } else {
+            None
+        }
+    }
+
+    /**
+    INTERNAL: Look up a cached entry through one of its indexes
+
+    # Arguments
+    - `index`: Name of the index, as registered with `with_index`
+    - `key`: The key to look for
+    */
+    pub(super) fn find(&self, index: &str, key: &str) -> Option<T> {
+        let (_, _, positions) = self.indexes.iter().find(|(name, _, _)| *name == index)?;
+
+        positions.get(key).map(|i| self.entries[*i].clone())
+    }
+
+    /**
+    INTERNAL: The cached entries, regardless of whether they have expired
+    */
+    pub(super) fn entries(&self) -> &[T] {
+        &self.entries
+    }
+
+    pub(super) fn is_populated(&self) -> bool {
+        self.fetched_at.is_some()
+    }
+
+    pub(super) fn set(&mut self, entries: Vec<T>) {
+        for (_, key, positions) in self.indexes.iter_mut() {
+            *positions = entries
+                .iter()
+                .enumerate()
+                .filter_map(|(i, entry)| key(entry).map(|k| (k, i)))
+                .collect();
+        }
+
+        self.entries = entries;
+        self.fetched_at = Some(Instant::now());
+    }
+
+    pub(super) fn set_ttl(&mut self, ttl: Duration) {
+        self.ttl = ttl;
+    }
+
+    pub(super) fn invalidate(&mut self) {
+        for (_, _, positions) in self.indexes.iter_mut() {
+            positions.clear();
+        }
+
+        self.entries.clear();
+        self.fetched_at = None;
+    }
+}
+
+/**
+INTERNAL: Bodies of GET responses keyed by URL together with their `ETag`,
+used to revalidate them through `If-None-Match`
+*/
+#[derive(Default)]
+pub(super) struct ETagCache {
+    entries: HashMap<String, (String, String)>,
+}
+
+impl ETagCache {
+    pub(super) fn etag(&self, url: &str) -> Option<String> {
+        self.entries.get(url).map(|(etag, _)| etag.clone())
+    }
+
+    pub(super) fn body(&self, url: &str) -> Option<String> {
+        self.entries.get(url).map(|(_, body)| body.clone())
+    }
+
+    pub(super) fn insert(&mut self, url: &str, etag: String, body: String) {
+        self.entries.insert(url.to_string(), (etag, body));
+    }
+}
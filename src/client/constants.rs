@@ -1,5 +1,21 @@
 use std::num::{NonZero, NonZeroU32};
+use std::time::Duration;
 
 pub(super) const BASE_URL: &str = "https://api.warframe.market/v2";
 pub(super) const V1_API: &str = "https://api.warframe.market/v1";
-pub(super) const REQUESTS_PER_SECOND: NonZeroU32 = NonZero::new(3).unwrap();
\ No newline at end of file
+pub(super) const STATIC_ASSETS_URL: &str = "https://warframe.market/static/assets";
+pub(super) const DEFAULT_LANGUAGE: &str = "en";
+pub(super) const REQUESTS_PER_SECOND: NonZeroU32 = NonZero::new(3).unwrap();
+
+pub(super) const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+pub(super) const ID_INDEX: &str = "id";
+pub(super) const GAME_REF_INDEX: &str = "game_ref";
+pub(super) const SLUG_INDEX: &str = "slug";
+
+pub(super) const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+pub(super) const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub(super) const DEFAULT_MAX_RETRIES: u32 = 3;
+pub(super) const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+pub(super) const RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
@@ -3,23 +3,20 @@ Provides a managed `Item` object, unlike the [`Item`][crate::types::item::Item]
 
 # Examples
 
-```rust
-use wf_market::{
-    client::Client,
-    utils::generate_device_id,
-};
+```rust,no_run
+use wf_market::client::Client;
 
 #[tokio::main]
 async fn main() {
-    let mut client = Client::new();
+    let client = Client::new();
 
     match client.get_items().await {
         Ok(mut items) => {
-            items = items.iter().filter(|item| item.is_sculpture()).collect();
+            items.retain(|item| item.is_sculpture());
             println!("Sculpture Valuation:");
             for item in items {
                 let sculpture = item.to_sculpture().unwrap();
-                println!("{}: {} endo", sculpture.get_name(), sculpture.calculate_value(None, None))
+                println!("{}: {} endo", sculpture.get_name(), sculpture.calculate_value(None, None).unwrap())
             }
         },
         Err(e) => println!("Error: {:?}", e),
@@ -28,16 +25,19 @@ async fn main() {
 ```
 */
 
+use super::constants::{DEFAULT_LANGUAGE, STATIC_ASSETS_URL};
+use crate::client::{Order, Unowned};
 use crate::error::ApiError;
-use crate::types::item::Item as ItemType;
+use crate::types::item::{Item as ItemType, ItemTranslation, OrderType};
+use crate::types::request::{OrderCreationRequest, OrderVariant};
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Regular;
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Mod {
     rank: u32
 }
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Sculpture {
     amber_stars: u32,
     cyan_stars: u32,
@@ -45,26 +45,75 @@ pub struct Sculpture {
     endo_multiplier: f32,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Item<State = Regular> {
-    object: ItemType,
+    pub(crate) object: ItemType,
     state: State,
 }
 
+/// Items are equal when the underlying items are, the conversion state is derived from it
+impl<State> PartialEq for Item<State> {
+    fn eq(&self, other: &Self) -> bool {
+        self.object == other.object
+    }
+}
+
 impl<State> Item<State> {
     pub fn get_type(&self) -> ItemType {
         self.object.clone()
     }
 
+    pub fn get_id(&self) -> String {
+        self.object.id.clone()
+    }
+
     pub fn get_slug(&self) -> String {
         self.object.slug.clone()
     }
 
+    /// The internal unique name the game uses for this item (e.g. `/Lotus/Powersuits/...`)
+    pub fn game_ref(&self) -> Option<String> {
+        self.object.game_ref.clone()
+    }
+
+    pub fn get_tags(&self) -> &[String] {
+        &self.object.tags
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.object.tags.iter().any(|t| t == tag)
+    }
+
+    /// The subtypes orders for this item can be placed with (e.g. `intact`, `radiant`), `None` if it has none
+    pub fn subtypes(&self) -> Option<&[String]> {
+        self.object.subtypes.as_deref()
+    }
+
     pub fn get_name(&self) -> String {
-        if let Some(en) = self.object.i18n.get("en") {
-            en.name.clone()
+        self.get_localized_name(DEFAULT_LANGUAGE)
+    }
+
+    /// The name in the given language (e.g. `de`), falling back to english
+    pub fn get_localized_name(&self, lang: &str) -> String {
+        if let Some(translation) = self.translation(lang) {
+            translation.name.clone()
         } else { String::new() }
     }
+
+    /// The fully qualified URL of the item's icon
+    pub fn get_icon_url(&self) -> Option<String> {
+        self.get_localized_icon_url(DEFAULT_LANGUAGE)
+    }
+
+    /// The fully qualified URL of the item's icon in the given language, falling back to english
+    pub fn get_localized_icon_url(&self, lang: &str) -> Option<String> {
+        self.translation(lang)
+            .map(|translation| format!("{}/{}", STATIC_ASSETS_URL, translation.icon.trim_start_matches('/')))
+    }
+
+    fn translation(&self, lang: &str) -> Option<&ItemTranslation> {
+        self.object.i18n.get(lang).or_else(|| self.object.i18n.get(DEFAULT_LANGUAGE))
+    }
 }
 
 impl Item<Regular> {
@@ -76,12 +125,8 @@ impl Item<Regular> {
     }
 
     pub fn to_sculpture(&self) -> Result<Item<Sculpture>, ApiError> {
-        let cyan_stars = if let Some(cyan) = self.object.max_cyan_stars {
-            cyan
-        } else { 0 };
-        let amber_stars = if let Some(amber) = self.object.max_amber_stars {
-            amber
-        } else { 0 };
+        let cyan_stars = self.object.max_cyan_stars.unwrap_or_default();
+        let amber_stars = self.object.max_amber_stars.unwrap_or_default();
 
         if let (Some(base_endo), Some(endo_multiplier)) = (
             self.object.base_endo, self.object.endo_multiplier) {
@@ -119,11 +164,137 @@ impl Item<Regular> {
     pub fn is_mod(&self) -> bool {
         self.object.max_rank.is_some()
     }
+
+    /// Whether the item is tagged `prime` (prime parts, blueprints and sets)
+    pub fn is_prime(&self) -> bool {
+        self.has_tag("prime")
+    }
+
+    /// Whether the item is tagged `arcane_enhancement`
+    pub fn is_arcane(&self) -> bool {
+        self.has_tag("arcane_enhancement")
+    }
+
+    /// Whether the item is tagged `relic` (void relics of every era)
+    pub fn is_relic(&self) -> bool {
+        self.has_tag("relic")
+    }
+
+    /// Whether the item is tagged `set`, or its slug ends in `_set` for untagged sets
+    pub fn is_set(&self) -> bool {
+        self.has_tag("set") || self.object.slug.ends_with("_set")
+    }
+
+    /**
+    Create an order request for this item, checking the variant against what the item allows
+
+    # Arguments
+    - `order_type`: Whether to buy or sell
+    - `platinum`: Price per item
+    - `quantity`: Amount of items
+    - `variant`: Rank, charges and subtype of the item, if applicable
+
+    # Returns
+    - A visible [`OrderCreationRequest`] for this item
+    - `ApiError::InvalidInput` if the price, quantity, rank, charges or subtype are out of range
+    */
+    pub fn order_request(
+        &self,
+        order_type: OrderType,
+        platinum: i32,
+        quantity: i32,
+        variant: OrderVariant,
+    ) -> Result<OrderCreationRequest, ApiError> {
+        if platinum <= 0 || quantity <= 0 {
+            return Err(ApiError::InvalidInput(format!(
+                "platinum and quantity must be positive, got {} and {}", platinum, quantity
+            )));
+        }
+
+        let mut request = OrderCreationRequest::new(&self.object.id, order_type, platinum, quantity, true);
+
+        if let Some(rank) = variant.rank {
+            match self.object.max_rank {
+                Some(max_rank) if u32::from(rank) <= max_rank => request = request.with_mods(rank),
+                Some(max_rank) => return Err(ApiError::InvalidInput(format!(
+                    "rank {} is above the max rank {} of {}", rank, max_rank, self.get_name()
+                ))),
+                None => return Err(ApiError::InvalidInput(format!("{} has no ranks", self.get_name()))),
+            }
+        }
+
+        if let Some(charges) = variant.charges {
+            match self.object.max_charges {
+                Some(max_charges) if u32::from(charges) <= max_charges => request = request.with_charges(charges),
+                Some(max_charges) => return Err(ApiError::InvalidInput(format!(
+                    "{} charges are above the max of {} for {}", charges, max_charges, self.get_name()
+                ))),
+                None => return Err(ApiError::InvalidInput(format!("{} has no charges", self.get_name()))),
+            }
+        }
+
+        if let Some(subtype) = variant.subtype {
+            match self.subtypes() {
+                Some(subtypes) if subtypes.contains(&subtype) => request = request.with_subtype(subtype),
+                Some(subtypes) => return Err(ApiError::InvalidInput(format!(
+                    "{} is not a subtype of {}, expected one of {}", subtype, self.get_name(), subtypes.join(", ")
+                ))),
+                None => return Err(ApiError::InvalidInput(format!("{} has no subtypes", self.get_name()))),
+            }
+        }
+
+        Ok(request)
+    }
+
+    /**
+    Calculate how many ducats are gained per platinum spent on this item
+
+    # Arguments
+    - `platinum`: The price paid for the item
+
+    # Returns
+    The ducats per platinum, `f32::INFINITY` for a price of 0 and `None` if the item can't be traded for ducats
+    */
+    pub fn ducats_per_platinum(&self, platinum: u32) -> Option<f32> {
+        let ducats = self.object.ducats?;
+
+        Some(match platinum {
+            0 => f32::INFINITY,
+            platinum => ducats as f32 / platinum as f32,
+        })
+    }
 }
 
 impl Item<Mod> {
+    /// Same as [`max_rank`][Item::max_rank], the name wrongly suggests a current rank
+    #[deprecated(note = "use `max_rank` instead")]
     pub fn get_rank(&self) -> u32 {
-        self.object.max_rank.unwrap_or(0)
+        self.state.rank
+    }
+
+    /// The highest rank this mod can be upgraded to
+    pub fn max_rank(&self) -> u32 {
+        self.state.rank
+    }
+
+    /// The number of charges a Requiem mod starts with, `None` for regular mods
+    pub fn max_charges(&self) -> Option<u32> {
+        self.object.max_charges
+    }
+
+    /// Whether the mod can be at the given rank (`0..=max_rank`)
+    pub fn is_valid_rank(&self, rank: u32) -> bool {
+        rank <= self.state.rank
+    }
+
+    /// The rarity of the mod (e.g. `common`, `rare`, `legendary`)
+    pub fn rarity(&self) -> Option<&str> {
+        self.object.rarity.as_deref()
+    }
+
+    /// The ducats the mod can be traded for at Baro Ki'Teer, `None` if it can't be
+    pub fn ducats(&self) -> Option<u32> {
+        self.object.ducats
     }
 }
 
@@ -135,34 +306,70 @@ impl Item<Sculpture> {
     - `cyan_stars`: Number of installed Cyan Stars, a value of None uses the max value
     - `amber_stars`: Number of installed Amber Stars, a value of None uses the max value
 
+    # Note
+    The value is `(base + 50 × cyan + 100 × amber) × (1 + multiplier × stars / sockets)`,
+    rounded to the nearest whole endo like in game
+
     # Returns
-    The total endo value of a sculpture with defined amount of stars installed
+    - The total endo value of a sculpture with defined amount of stars installed
+    - `ApiError::ParsingError` if the sculpture has no sockets
+    - `ApiError::InvalidInput` if more stars are given than the sculpture has sockets for
     */
-    pub fn calculate_value(&self, cyan_stars: Option<u32>, amber_stars: Option<u32>) -> u32 {
+    pub fn calculate_value(&self, cyan_stars: Option<u32>, amber_stars: Option<u32>) -> Result<u32, ApiError> {
         let base: f32 = self.state.base_endo as f32;
         let multiplier = self.state.endo_multiplier;
         let sockets = self.state.amber_stars + self.state.cyan_stars;
 
-        let cyan = if cyan_stars.is_some() {
-            cyan_stars.unwrap()
-        } else {
-            self.state.cyan_stars
-        };
-
-        let amber = if amber_stars.is_some() {
-            amber_stars.unwrap()
-        } else {
-            self.state.amber_stars
-        };
+        let cyan = cyan_stars.unwrap_or(self.state.cyan_stars);
+        let amber = amber_stars.unwrap_or(self.state.amber_stars);
 
         if sockets == 0 {
-            panic!("Ayatan Sculpture has an invalid amount of sockets");
+            return Err(ApiError::ParsingError(String::from("Ayatan Sculpture has an invalid amount of sockets")));
+        }
+        if cyan > self.state.cyan_stars {
+            return Err(ApiError::InvalidInput(format!(
+                "{} cyan stars given, {} has {} cyan sockets", cyan, self.get_name(), self.state.cyan_stars
+            )));
+        }
+        if amber > self.state.amber_stars {
+            return Err(ApiError::InvalidInput(format!(
+                "{} amber stars given, {} has {} amber sockets", amber, self.get_name(), self.state.amber_stars
+            )));
+        }
+        if cyan + amber > sockets {
+            return Err(ApiError::InvalidInput(format!(
+                "{} stars given, {} has {} sockets", cyan + amber, self.get_name(), sockets
+            )));
         }
 
         let total_stars = (cyan + amber) as f32;
         let base_part = base + 50.0 * (cyan as f32) + 100.0 * (amber as f32);
         let socket_factor = 1.0 + multiplier * total_stars / (sockets as f32);
 
-        (base_part * socket_factor) as u32
+        // The game rounds to the nearest endo, truncating would be off by one whenever
+        // the float product lands just below a whole number (e.g. 2404.9998 for 2405)
+        Ok((base_part * socket_factor).round() as u32)
+    }
+
+    /**
+    Calculate how much endo an order of this sculpture yields per platinum spent
+
+    # Arguments
+    - `order`: An order of this sculpture, stars not specified on the order are assumed to be installed
+
+    # Returns
+    - The endo per platinum, `f32::INFINITY` for orders priced at 0 platinum
+    - `ApiError::ParsingError` if the sculpture has no sockets
+    */
+    pub fn endo_per_platinum(&self, order: &Order<Unowned>) -> Result<f32, ApiError> {
+        let value = self.calculate_value(
+            order.object.cyan_stars.map(u32::from),
+            order.object.amber_stars.map(u32::from),
+        )?;
+
+        Ok(match order.get_platinum() {
+            0 => f32::INFINITY,
+            platinum => value as f32 / platinum as f32,
+        })
     }
 }
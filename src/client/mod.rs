@@ -11,12 +11,13 @@ let client = Client::new();
 ```
 
 Running authenticated:
-```rust
+```rust,no_run
 use wf_market::{
     client::Client,
     utils::generate_device_id,
 };
 
+#[tokio::main]
 async fn main() {
     let client = {
         // device_id should be stored and reused
@@ -32,47 +33,69 @@ async fn main() {
 
 // Submodules
 mod auth;
+mod batch;
+mod cache;
 mod constants;
 mod item;
 mod order;
+mod page;
+mod pricing;
 mod riven;
+mod transport;
 mod utils;
 pub mod ws;
 
-use crate::error::{ApiError, ApiErrorBody, ErrorResponse};
-use crate::types::filter::OrdersTopFilters;
-use crate::types::http::ApiResult;
-use crate::types::item::{Item as ItemObject, Order as OrderItem, OrderWithUser, OrdersTopResult};
+use crate::error::{ApiError, ErrorResponse};
+use crate::types::filter::{ItemSearch, OrdersFilters, OrdersTopFilters};
+use crate::types::http::{APIV1Result, ApiResult};
+use crate::types::item::{
+    Item as ItemObject, Order as OrderItem, OrderType, OrderWithUser, OrdersTopResult, Relic,
+};
 use crate::types::riven::Riven as RivenObject;
-use crate::types::user::{FullUser, StatusType};
+use crate::types::statistics::ItemStatistics;
+use crate::types::user::{FullUser, PublicUser, StatusType};
 use governor::RateLimiter;
 use governor::clock::DefaultClock;
 use governor::state::{InMemoryState, NotKeyed};
 use reqwest::Method as HttpMethod;
+use reqwest::header::{ETAG, IF_NONE_MATCH};
 use serde::Serialize;
+use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
-pub use auth::*;
+pub use batch::*;
+use cache::{Cache, ETagCache};
 use constants::*;
 pub use item::*;
 pub use order::*;
+pub use page::*;
+pub use pricing::*;
 pub use riven::*;
+pub use transport::*;
 use utils::*;
 
+#[derive(Debug)]
 pub struct Unauthenticated;
+#[derive(Debug)]
 pub struct Authenticated;
 
 pub struct Client<State = Unauthenticated> {
     pub(crate) http: reqwest::Client,
+    transport: Option<Arc<dyn HttpTransport>>,
     pub user: Option<FullUser>,
     pub orders: Vec<Order<Owned>>,
     pub status: StatusType,
-    items_cache: Vec<Item>,
-    rivens_cache: Vec<Riven>,
-    token: Option<String>,
+    items_cache: RwLock<Cache<Item>>,
+    rivens_cache: RwLock<Cache<Riven>>,
+    token: RwLock<Option<String>>,
     device_id: Option<String>,
+    credentials: Option<(String, String)>,
+    http_config: HttpConfig,
     limiter: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock>>,
+    max_retries: u32,
+    etags: Option<RwLock<ETagCache>>,
+    batch_options: BatchOptions,
     _state: PhantomData<State>,
 }
 
@@ -91,6 +114,9 @@ impl<State> Client<State> {
     /**
     INTERNAL: Makes a request to the API, returning the response as a deserialized type.
 
+    GET requests are retried up to `max_retries` times on connection errors and
+    HTTP 429/503 responses, other methods are only attempted once to avoid creating
+    duplicate orders.
 
     # Arguments
     - `method`: The HTTP method to use (GET, POST, PUT, DELETE).
@@ -99,6 +125,7 @@ impl<State> Client<State> {
 
     # Returns
     - A `Result` containing the deserialized response or an `ApiError` on failure.
+    - `ApiError::TokenExpired` if the request was sent with a token and rejected as unauthorized
     */
     pub(crate) async fn call_api<T: serde::de::DeserializeOwned>(
         &self,
@@ -106,92 +133,454 @@ impl<State> Client<State> {
         path: &str,
         body: Option<&impl Serialize>,
     ) -> Result<T, ApiError> {
-        let url = BASE_URL.to_owned() + path;
-        let builder = self.http.request(transform_method(method), &url);
+        self.call_url(method, BASE_URL.to_owned() + path, body)
+            .await
+    }
+
+    /**
+    INTERNAL: Same as `call_api`, but against the legacy v1 API for endpoints
+    which are not yet available on v2
+    */
+    pub(crate) async fn call_api_v1<T: serde::de::DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&impl Serialize>,
+    ) -> Result<T, ApiError> {
+        self.call_url(method, V1_API.to_owned() + path, body)
+            .await
+    }
+
+    async fn call_url<T: serde::de::DeserializeOwned>(
+        &self,
+        method: Method,
+        url: String,
+        body: Option<&impl Serialize>,
+    ) -> Result<T, ApiError> {
+        let method = transform_method(method);
+
+        match self.send_url(method.clone(), &url, body).await {
+            Err(ApiError::TokenExpired) if self.reauthenticate().await => {
+                self.send_url(method, &url, body).await
+            }
+            result => result,
+        }
+    }
 
-        let builder = if let Some(body) = body {
-            builder.json(body)
+    async fn send_url<T: serde::de::DeserializeOwned>(
+        &self,
+        method: HttpMethod,
+        url: &str,
+        body: Option<&impl Serialize>,
+    ) -> Result<T, ApiError> {
+        let token = self.token.read().unwrap().clone();
+        let max_retries = if method == HttpMethod::GET {
+            self.max_retries
         } else {
-            builder
+            0
         };
 
-        self.limiter.until_ready().await;
+        let etags = self.etags.as_ref().filter(|_| method == HttpMethod::GET);
+        let cached_etag = etags.and_then(|etags| etags.read().unwrap().etag(url));
 
-        match builder.send().await {
-            Ok(resp) => {
-                let status = resp.status();
+        let mut attempt = 0;
+        let response = loop {
+            let builder = with_headers(
+                self.http.request(method.clone(), url),
+                token.as_deref(),
+            );
 
-                let body = resp
-                    .text()
-                    .await
-                    .map_err(|_| ApiError::Unknown("Error".to_string()))?;
+            let builder = match &cached_etag {
+                Some(etag) => builder.header(IF_NONE_MATCH, etag),
+                None => builder,
+            };
 
-                // Check if the status code indicates an error
-                match status {
-                    reqwest::StatusCode::OK | reqwest::StatusCode::CREATED => {}
-                    reqwest::StatusCode::UNAUTHORIZED => {
-                        return Err(ApiError::Unauthorized);
-                    }
-                    reqwest::StatusCode::NOT_FOUND => {
-                        return Err(ApiError::NotFound(format!(
-                            "Resource not found: {}, Message: {}",
-                            url, body
-                        )));
-                    }
-                    reqwest::StatusCode::BAD_REQUEST | reqwest::StatusCode::FORBIDDEN => {
-                        match serde_json::from_str::<ErrorResponse>(&body) {
-                            Ok(api_result) => {
-                                return Err(ApiError::WFMError(api_result));
-                            }
-                            Err(e) => {
-                                return Err(ApiError::ParsingError(
-                                    format!(
-                                        "Error Parsing Bad Request Error: {:?}, Message: {}",
-                                        e, body
-                                    )
-                                    .to_string(),
-                                ));
-                            }
-                        }
-                    }
-                    _ => {
-                        return Err(ApiError::Unknown(format!(
-                            "Unexpected status code: {}",
-                            status
-                        )));
-                    }
-                }
+            let builder = if let Some(body) = body {
+                builder.json(body)
+            } else {
+                builder
+            };
 
-                let data = serde_json::from_str::<T>(&body);
+            self.limiter.until_ready().await;
 
-                match data {
-                    Ok(data) => Ok(data),
-                    Err(err) => Err(ApiError::ParsingError(
-                        format!("Error Parsing: {:?}, Body: {}", err, body).to_string(),
-                    )),
+            match self.send_request(builder.build()?).await {
+                Ok(resp) if attempt < max_retries && is_retryable_status(resp.status) => {
+                    let retry_after = parse_retry_after(&resp.headers);
+                    tokio::time::sleep(backoff_delay(attempt, retry_after)).await;
+                }
+                Err(ApiError::Request(e))
+                    if attempt < max_retries && (e.is_connect() || e.is_timeout()) =>
+                {
+                    tokio::time::sleep(backoff_delay(attempt, None)).await;
                 }
+                result => break result,
+            }
+
+            attempt += 1;
+        };
+
+        let resp = response?;
+        let mut status = resp.status;
+        let etag = resp
+            .headers
+            .get(ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_owned);
+        let mut body = resp.body;
+
+        // The body we revalidated is still current
+        if status == reqwest::StatusCode::NOT_MODIFIED
+            && let Some(cached) = etags.and_then(|etags| etags.read().unwrap().body(url))
+        {
+            status = reqwest::StatusCode::OK;
+            body = cached;
+        }
+
+        // Check if the status code indicates an error
+        match status {
+            reqwest::StatusCode::OK | reqwest::StatusCode::CREATED => {}
+            // A token which used to be accepted is only rejected once it expired (or was signed out)
+            reqwest::StatusCode::UNAUTHORIZED if token.is_some() => {
+                return Err(ApiError::TokenExpired);
+            }
+            reqwest::StatusCode::UNAUTHORIZED => {
+                return Err(ApiError::Unauthorized);
+            }
+            reqwest::StatusCode::NOT_FOUND => {
+                return Err(ApiError::NotFound(format!(
+                    "Resource not found: {}, Message: {}",
+                    url, body
+                )));
+            }
+            reqwest::StatusCode::BAD_REQUEST | reqwest::StatusCode::FORBIDDEN => {
+                // Fall back to the raw response should the error not be in the usual shape
+                return match serde_json::from_str::<ErrorResponse>(&body) {
+                    Ok(api_result) => Err(ApiError::WFMError(api_result)),
+                    Err(_) => Err(ApiError::Http {
+                        status: status.as_u16(),
+                        body,
+                    }),
+                };
+            }
+            _ => {
+                return Err(ApiError::Http {
+                    status: status.as_u16(),
+                    body,
+                });
             }
-            Err(_) => Err(ApiError::RequestError),
         }
+
+        if let (Some(etags), Some(etag)) = (etags, etag) {
+            etags.write().unwrap().insert(url, etag, body.clone());
+        }
+
+        Ok(serde_json::from_str::<T>(&body)?)
+    }
+
+    /**
+    INTERNAL: Send a request through the custom transport if one is set, the HTTP client otherwise
+    */
+    pub(super) async fn send_request(
+        &self,
+        request: reqwest::Request,
+    ) -> Result<HttpResponse, ApiError> {
+        match &self.transport {
+            Some(transport) => transport.send(request).await,
+            None => HttpTransport::send(&self.http, request).await,
+        }
+    }
+
+    /**
+    Check that the WFM API is reachable, e.g. to fail fast on startup
+
+    # Note
+    Fetches the small `/versions` resource, the request goes through the rate limiter
+    and is retried like any other GET request
+
+    # Returns
+    - `Ok(())` if the API responded successfully
+    */
+    pub async fn ping(&self) -> Result<(), ApiError> {
+        let _: serde_json::Value = self
+            .call_api(Method::Get, "/versions", None::<&NoBody>)
+            .await?;
+
+        Ok(())
     }
 
     /**
     Fetch all listed items from the WFM API
 
+    # Note
+    The result is cached and reused until the cache TTL expires
+    (see [`with_cache_ttl`][Client::with_cache_ttl]) or the cache is invalidated
+
     # Returns
     List of all listed items
     */
     pub async fn get_items(&self) -> Result<Vec<Item<Regular>>, ApiError> {
-        if !self.items_cache.is_empty() {
-            let mut new_items = Vec::new();
-            new_items.clone_from(&self.items_cache);
-            return Ok(new_items);
+        if let Some(items) = self.items_cache.read().unwrap().get() {
+            return Ok(items);
         }
 
+        self.fetch_items().await
+    }
+
+    /**
+    Fetch a single page of the listed items
+
+    # Note
+    WFM returns every item at once, pages are cut from the (cached) full list
+
+    # Arguments
+    - `offset`: Index of the first item on the page
+    - `limit`: Maximum amount of items on the page
+
+    # Returns
+    The requested [`Page`] of items
+    */
+    pub async fn get_all_items_paged(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Page<Item<Regular>>, ApiError> {
+        Ok(Page::slice(self.get_items().await?, offset, limit))
+    }
+
+    /**
+    INTERNAL: Fetch the item list from the API, bypassing and repopulating the cache
+    */
+    async fn fetch_items(&self) -> Result<Vec<Item<Regular>>, ApiError> {
         let items: Result<ApiResult<Vec<ItemObject>>, ApiError> =
             self.call_api(Method::Get, "/items", None::<&NoBody>).await;
 
-        Ok(items?.data.iter().map(|item| Item::new(item)).collect())
+        let items: Vec<Item<Regular>> = items?.data.iter().map(Item::new).collect();
+        self.items_cache.write().unwrap().set(items.clone());
+
+        Ok(items)
+    }
+
+    /**
+    Find an item by the internal unique name the game uses for it,
+    useful to price items detected from the game client (e.g. through EE.log)
+
+    # Arguments
+    - `game_ref`: Internal unique name of the item (e.g. `/Lotus/Powersuits/...`)
+
+    # Returns
+    The matching item, if WFM lists it
+    */
+    pub async fn item_by_game_ref(
+        &self,
+        game_ref: &str,
+    ) -> Result<Option<Item<Regular>>, ApiError> {
+        self.get_items().await?;
+
+        Ok(self
+            .items_cache
+            .read()
+            .unwrap()
+            .find(GAME_REF_INDEX, game_ref))
+    }
+
+    /**
+    Find an item by its WFM id, e.g. the `item_id` of an order
+
+    # Arguments
+    - `id`: The WFM id of the item
+
+    # Returns
+    The matching item, if WFM lists it
+    */
+    pub async fn get_item_by_id(&self, id: &str) -> Result<Option<Item<Regular>>, ApiError> {
+        self.get_items().await?;
+
+        Ok(self.items_cache.read().unwrap().find(ID_INDEX, id))
+    }
+
+    /**
+    Look up an item by its slug in the cached item list, without fetching it
+
+    # Note
+    Expired entries are still returned, `None` is returned if nothing has been cached yet
+    (see [`get_items`][Client::get_items]) or the cache was invalidated
+
+    # Arguments
+    - `slug`: The slug of the item
+
+    # Returns
+    The matching item, if cached
+    */
+    pub fn find_by_slug(&self, slug: &str) -> Option<Item<Regular>> {
+        self.items_cache.read().unwrap().find(SLUG_INDEX, slug)
+    }
+
+    /**
+    Look up an item by its WFM id in the cached item list, without fetching it,
+    see [`find_by_slug`][Client::find_by_slug]
+
+    # Arguments
+    - `id`: The WFM id of the item
+
+    # Returns
+    The matching item, if cached
+    */
+    pub fn find_by_id(&self, id: &str) -> Option<Item<Regular>> {
+        self.items_cache.read().unwrap().find(ID_INDEX, id)
+    }
+
+    /**
+    Search the item list, filtering is done client-side against the cached items
+
+    # Arguments
+    - `query`: The [`ItemSearch`][crate::types::filter::ItemSearch] criteria to match
+
+    # Returns
+    All items matching the search
+    */
+    pub async fn search_items(&self, query: &ItemSearch) -> Result<Vec<Item<Regular>>, ApiError> {
+        Ok(self
+            .get_items()
+            .await?
+            .into_iter()
+            .filter(|item| query.matches(&item.object))
+            .collect())
+    }
+
+    /**
+    Get every tradable item that is not vaulted, filtered client-side against the cached items
+
+    # Note
+    WFM always returns the full item list, items missing the `tradable` or `vaulted` fields
+    are treated as tradable and not vaulted, see [`ItemSearch`][crate::types::filter::ItemSearch]
+
+    # Returns
+    All tradable, non-vaulted items
+    */
+    pub async fn get_tradable_items(&self) -> Result<Vec<Item<Regular>>, ApiError> {
+        self.search_items(&ItemSearch {
+            tradable: Some(true),
+            vaulted: Some(false),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /**
+    Get every vaulted item, filtered client-side against the cached items
+
+    # Returns
+    All items marked as vaulted
+    */
+    pub async fn get_vaulted_items(&self) -> Result<Vec<Item<Regular>>, ApiError> {
+        self.search_items(&ItemSearch {
+            vaulted: Some(true),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /**
+    Value every Ayatan Sculpture with all stars installed, most endo first
+
+    # Note
+    Sculptures without sockets can't be valued and are skipped
+
+    # Returns
+    Every sculpture paired with its endo value, sorted by value descending
+    */
+    pub async fn rank_sculptures_by_value(&self) -> Result<Vec<(Item<Sculpture>, u32)>, ApiError> {
+        let mut sculptures: Vec<(Item<Sculpture>, u32)> = self
+            .get_items()
+            .await?
+            .iter()
+            .filter_map(|item| item.to_sculpture().ok())
+            .filter_map(|sculpture| {
+                let value = sculpture.calculate_value(None, None).ok()?;
+                Some((sculpture, value))
+            })
+            .collect();
+
+        sculptures.sort_by_key(|(_, value)| std::cmp::Reverse(*value));
+        Ok(sculptures)
+    }
+
+    /**
+    Fetch items which have been added since the item list was last cached
+
+    # Note
+    WFM does not expose a "recently added" endpoint, so this is derived by diffing a freshly
+    fetched item list against the cached one (which is then replaced). If nothing has been cached
+    yet, or the cache was invalidated, there is nothing to compare against and no items are returned.
+
+    # Arguments
+    - `limit`: Maximum amount of items to return
+
+    # Returns
+    Items not present in the previously cached list
+    */
+    pub async fn recent_items(&self, limit: usize) -> Result<Vec<Item<Regular>>, ApiError> {
+        let known: Option<HashSet<String>> = {
+            let cache = self.items_cache.read().unwrap();
+            cache
+                .is_populated()
+                .then(|| cache.entries().iter().map(|item| item.get_id()).collect())
+        };
+
+        let items = self.fetch_items().await?;
+
+        Ok(match known {
+            Some(known) => items
+                .into_iter()
+                .filter(|item| !known.contains(&item.get_id()))
+                .take(limit)
+                .collect(),
+            None => Vec::new(),
+        })
+    }
+
+    /**
+    Clear the cached item list, the next call to `get_items()` will fetch from the API
+    */
+    pub fn invalidate_items_cache(&self) {
+        self.items_cache.write().unwrap().invalidate();
+    }
+
+    /**
+    Populate the item cache from a snapshot, e.g. one saved with
+    [`items_to_json`][Client::items_to_json] on a previous run, to skip fetching the item list on startup
+
+    # Note
+    The snapshot is treated as freshly fetched, it is replaced once the cache TTL expires
+
+    # Arguments
+    - `json`: A JSON array of items, in the same format as the `data` of the WFM `/items` endpoint
+
+    # Returns
+    - `ApiError::Parse` if the snapshot is not a valid item list
+    */
+    pub fn load_items_from_json(&self, json: &str) -> Result<(), ApiError> {
+        let items: Vec<ItemObject> = serde_json::from_str(json)?;
+
+        self.items_cache
+            .write()
+            .unwrap()
+            .set(items.iter().map(Item::new).collect());
+        Ok(())
+    }
+
+    /**
+    Save the cached item list as JSON, to be loaded again with
+    [`load_items_from_json`][Client::load_items_from_json]
+
+    # Returns
+    A JSON array of the cached items, empty if nothing has been cached yet
+    */
+    pub fn items_to_json(&self) -> String {
+        let cache = self.items_cache.read().unwrap();
+        let items: Vec<&ItemObject> = cache.entries().iter().map(|item| &item.object).collect();
+
+        serde_json::to_string(&items).expect("items are always serializable")
     }
 
     /**
@@ -212,6 +601,34 @@ impl<State> Client<State> {
         Ok(Item::new(&items?.data))
     }
 
+    /**
+    Fetch a void relic
+
+    # Note
+    WFM does not publish relic reward tables, so `rewards` is always empty,
+    see [`Relic`][crate::types::item::Relic]
+
+    # Arguments
+    - `slug`: The slug of the relic (e.g. `lith_a1_relic`)
+
+    # Returns
+    - The [`Relic`][crate::types::item::Relic]
+    - `ApiError::InvalidInput` if the item is not a relic
+    */
+    pub async fn get_relic(&self, slug: &str) -> Result<Relic, ApiError> {
+        let item = self.get_item(slug).await?;
+
+        if !item.has_tag("relic") {
+            return Err(ApiError::InvalidInput(format!("{} is not a relic", slug)));
+        }
+
+        Ok(Relic {
+            id: item.get_id(),
+            slug: item.get_slug(),
+            rewards: Vec::new(),
+        })
+    }
+
     /**
     Fetch all orders from users online within the last 7 days
 
@@ -222,6 +639,28 @@ impl<State> Client<State> {
     A list of orders
     */
     pub async fn get_orders(&self, slug: &str) -> Result<Vec<Order<Unowned>>, ApiError> {
+        self.get_orders_filtered(slug, OrdersFilters::default())
+            .await
+    }
+
+    /**
+    Fetch all orders from users online within the last 7 days, matching the given filters
+
+    # Note
+    WFM always returns every order, the filters are applied client-side
+
+    # Arguments
+    - `slug`: The item whose orders you want to fetch
+    - `filters`: The [`OrdersFilters`][crate::types::filter::OrdersFilters] the orders must match
+
+    # Returns
+    A list of matching orders
+    */
+    pub async fn get_orders_filtered(
+        &self,
+        slug: &str,
+        filters: OrdersFilters,
+    ) -> Result<Vec<Order<Unowned>>, ApiError> {
         let items: Result<ApiResult<Vec<OrderWithUser>>, ApiError> = self
             .call_api(
                 Method::Get,
@@ -233,10 +672,66 @@ impl<State> Client<State> {
         Ok(items?
             .data
             .iter()
-            .map(|order| Order::new(&order.downgrade()))
+            .filter(|order| filters.matches(order))
+            .map(Order::with_user)
             .collect())
     }
 
+    /**
+    Fetch the orders of multiple items concurrently, sharing the clients rate limiter
+
+    # Note
+    Runs according to the clients [`BatchOptions`], with `BatchOrdering::AsCompleted`
+    the results are no longer in the same order as `slugs`
+
+    # Arguments
+    - `slugs`: The items whose orders you want to fetch
+
+    # Returns
+    The slug of every item paired with its orders, a failed fetch does not abort the others
+    */
+    pub async fn get_orders_for_items(
+        &self,
+        slugs: &[&str],
+    ) -> Vec<(String, Result<Vec<Order<Unowned>>, ApiError>)> {
+        let tasks: Vec<_> = slugs
+            .iter()
+            .map(|slug| async move { (slug.to_string(), self.get_orders(slug).await) })
+            .collect();
+
+        run_batch(self.batch_options, tasks).await
+    }
+
+    /**
+    Fetch a single page of the orders matching the given filters
+
+    # Note
+    WFM returns every order at once, pages are cut from the full list
+    so every call fetches the orders again
+
+    # Arguments
+    - `slug`: The item whose orders you want to fetch
+    - `filters`: The [`OrdersFilters`][crate::types::filter::OrdersFilters] the orders must match
+    - `offset`: Index of the first order on the page
+    - `limit`: Maximum amount of orders on the page
+
+    # Returns
+    The requested [`Page`] of orders
+    */
+    pub async fn get_orders_paged(
+        &self,
+        slug: &str,
+        filters: OrdersFilters,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Page<Order<Unowned>>, ApiError> {
+        Ok(Page::slice(
+            self.get_orders_filtered(slug, filters).await?,
+            offset,
+            limit,
+        ))
+    }
+
     /**
     Fetch the top 5 orders for the specified slug
 
@@ -251,7 +746,34 @@ impl<State> Client<State> {
         slug: &str,
         filters: Option<OrdersTopFilters>,
     ) -> Result<Vec<Order<Unowned>>, ApiError> {
-        let query: String = if let Some(filters) = filters.clone() {
+        let top = self.get_orders_top_split(slug, filters).await?;
+
+        Ok([top.buy, top.sell].concat())
+    }
+
+    /**
+    Fetch the top 5 orders for the specified slug, keeping buy and sell orders apart
+
+    # Arguments
+    - `slug`: The item whose orders you want to fetch
+    - `filters`: Optional [`OrdersTopFilters`][crate::types::filter::OrdersTopFilters]
+
+    # Returns
+    - A [`TopOrders`][crate::client::order::TopOrders] with buy orders sorted by price descending
+      and sell orders sorted by price ascending
+    - `ApiError::InvalidInput` if the filters contradict each other or can't match the (cached) item,
+      see [`OrdersTopFilters::validate`][crate::types::filter::OrdersTopFilters::validate]
+    */
+    pub async fn get_orders_top_split(
+        &self,
+        slug: &str,
+        filters: Option<OrdersTopFilters>,
+    ) -> Result<TopOrders, ApiError> {
+        if let Some(filters) = &filters {
+            filters.validate(self.find_by_slug(slug).as_ref().map(|item| &item.object))?;
+        }
+
+        let query: String = if let Some(filters) = &filters {
             let params = serde_urlencoded::to_string(filters)
                 .map_err(|_| ApiError::ParsingError("Unable to serialize filters".to_string()))?;
             format!("?{}", params)
@@ -269,34 +791,13 @@ impl<State> Client<State> {
 
         let data = items?.data;
 
-        let is_filtering_status = if let Some(filters) = filters.clone() {
-            filters.user_activity.is_some()
-        } else {
-            false
-        };
-
-        let buy: Vec<Order<Unowned>> = data
-            .buy
-            .iter()
-            .filter(|o| {
-                is_filtering_status
-                    && o.user.status_type == filters.clone().unwrap().user_activity.unwrap()
-            })
-            .map(|order| Order::new(&order.downgrade()))
-            .collect();
-        let sell: Vec<Order<Unowned>> = data
-            .sell
-            .iter()
-            .filter(|o| {
-                is_filtering_status
-                    && o.user.status_type == filters.clone().unwrap().user_activity.unwrap()
-            })
-            .map(|order| Order::new(&order.downgrade()))
-            .collect();
+        // No filters means every order is kept
+        let keep = |o: &&OrderWithUser| filters.as_ref().is_none_or(|f| f.matches_user(&o.user));
 
-        let total: Vec<Order<Unowned>> = [buy, sell].concat();
+        let buy: Vec<Order<Unowned>> = data.buy.iter().filter(keep).map(Order::with_user).collect();
+        let sell: Vec<Order<Unowned>> = data.sell.iter().filter(keep).map(Order::with_user).collect();
 
-        Ok(total)
+        Ok(TopOrders { buy, sell })
     }
 
     /**
@@ -309,16 +810,128 @@ impl<State> Client<State> {
     A managed [`Item`][crate::client::item::Item] object
     */
     pub async fn get_order_item(&self, order: &Order) -> Result<Item<Regular>, ApiError> {
-        if let Some(item) = self
-            .get_items()
+        self.get_item_by_id(&order.object.item_id)
             .await?
-            .iter()
-            .find(|i| i.get_type().id == order.object.item_id)
-        {
-            return Ok(item.clone());
+            .ok_or_else(|| ApiError::Unknown("Item not found".to_string()))
+    }
+
+    /**
+    Find the orders of other users which fill an order, e.g. buyers paying at least
+    the price of one of your sell orders
+
+    # Note
+    Only visible orders of the same rank and subtype (if the order has one) are considered
+
+    # Arguments
+    - `order`: The order to find matches for, usually one of your own
+
+    # Returns
+    - Opposite orders whose price crosses the orders price, best price first
+    - `ApiError::Unknown` if the ordered item is not in the item list
+    */
+    pub async fn find_matches<OrderState>(
+        &self,
+        order: &Order<OrderState>,
+    ) -> Result<Vec<Order<Unowned>>, ApiError> {
+        let item = self
+            .get_item_by_id(&order.object.item_id)
+            .await?
+            .ok_or_else(|| ApiError::Unknown("Item not found".to_string()))?;
+
+        let wanted = order.get_sell_type().opposite();
+
+        let mut matches: Vec<Order<Unowned>> = self
+            .get_orders(&item.get_slug())
+            .await?
+            .into_iter()
+            .filter(|o| {
+                o.get_visible()
+                    && o.get_sell_type() == wanted
+                    && o.get_id() != order.object.id
+                    && order.get_rank().is_none_or(|rank| o.get_rank() == Some(rank))
+                    && order.get_subtype().is_none_or(|subtype| o.get_subtype() == Some(subtype))
+                    && match wanted {
+                        OrderType::Buy => o.get_platinum() >= order.get_platinum(),
+                        OrderType::Sell => o.get_platinum() <= order.get_platinum(),
+                    }
+            })
+            .collect();
+
+        match wanted {
+            OrderType::Buy => matches.sort_by_key(|o| std::cmp::Reverse(o.get_platinum())),
+            OrderType::Sell => matches.sort_by_key(|o| o.get_platinum()),
         }
 
-        Err(ApiError::Unknown("Item not found".to_string()))
+        Ok(matches)
+    }
+
+    /**
+    Resolve the item, market context and seller reputation of a batch of orders,
+    e.g. to process a live order feed
+
+    # Note
+    Items are resolved from the cached item list and statistics are fetched once per distinct item,
+    an item whose statistics can't be fetched is simply left without market context
+
+    # Arguments
+    - `orders`: The orders to enrich
+
+    # Returns
+    The enriched orders, in the same order as given
+    */
+    pub async fn enrich_orders(
+        &self,
+        orders: Vec<Order<Unowned>>,
+    ) -> Result<Vec<EnrichedOrder>, ApiError> {
+        self.get_items().await?;
+
+        let items: HashMap<String, Option<Item<Regular>>> = {
+            let cache = self.items_cache.read().unwrap();
+            orders
+                .iter()
+                .map(|o| (o.object.item_id.clone(), cache.find(ID_INDEX, &o.object.item_id)))
+                .collect()
+        };
+
+        let lookups: Vec<_> = items
+            .iter()
+            .map(|(id, item)| async move {
+                let median = match item {
+                    Some(item) => self
+                        .get_item_statistics(&item.get_slug())
+                        .await
+                        .ok()
+                        .and_then(|stats| stats.closed.last_48_hours.last().map(|e| e.median)),
+                    None => None,
+                };
+                (id.clone(), median)
+            })
+            .collect();
+
+        let medians: HashMap<String, Option<f64>> = run_batch(self.batch_options, lookups)
+            .await
+            .into_iter()
+            .collect();
+
+        Ok(orders
+            .into_iter()
+            .map(|order| {
+                let item_id = &order.object.item_id;
+                let market_median = medians.get(item_id).copied().flatten();
+                let platinum = order.get_platinum() as f64;
+
+                EnrichedOrder {
+                    item: items.get(item_id).cloned().flatten(),
+                    market_median,
+                    is_deal: market_median.is_some_and(|median| match order.get_sell_type() {
+                        OrderType::Sell => platinum < median,
+                        OrderType::Buy => platinum > median,
+                    }),
+                    seller_reputation: order.get_user().map(|u| u.reputation),
+                    order,
+                }
+            })
+            .collect())
     }
 
     /**
@@ -341,24 +954,148 @@ impl<State> Client<State> {
 
         Ok(Order::new(&order?.data))
     }
+    /**
+    Fetch the trade statistics of an item
+
+    # Note
+    Statistics are only available through the v1 API
+
+    # Arguments
+    - `slug`: The item whose statistics you want to fetch
+
+    # Returns
+    Closed (completed trades) and live (listed orders) statistics for the last 48 hours and 90 days
+    */
+    pub async fn get_item_statistics(&self, slug: &str) -> Result<ItemStatistics, ApiError> {
+        let statistics: Result<APIV1Result<ItemStatistics>, ApiError> = self
+            .call_api_v1(
+                Method::Get,
+                format!("/items/{}/statistics", slug).as_str(),
+                None::<&NoBody>,
+            )
+            .await;
+
+        Ok(statistics?.payload)
+    }
+
+    /**
+    Fetch the top orders and the trade statistics of an item at the same time
+
+    # Arguments
+    - `slug`: The item whose orders and statistics you want to fetch
+    - `filters`: Optional [`OrdersTopFilters`][crate::types::filter::OrdersTopFilters] for the top orders
+
+    # Returns
+    An [`OrdersWithStats`][crate::client::order::OrdersWithStats], fails if either request fails
+    */
+    pub async fn get_item_orders_with_stats(
+        &self,
+        slug: &str,
+        filters: Option<OrdersTopFilters>,
+    ) -> Result<OrdersWithStats, ApiError> {
+        let (top_orders, stats) = tokio::try_join!(
+            self.get_orders_top_split(slug, filters),
+            self.get_item_statistics(slug),
+        )?;
+
+        Ok(OrdersWithStats { top_orders, stats })
+    }
+
+    /**
+    Fetch the public profile of a user
+
+    # Arguments
+    - `name`: The users slug, usually their in-game name in lowercase
+
+    # Returns
+    The users public profile
+    */
+    pub async fn get_user(&self, name: &str) -> Result<PublicUser, ApiError> {
+        let user: Result<ApiResult<PublicUser>, ApiError> = self
+            .call_api(
+                Method::Get,
+                format!("/user/{}", name).as_str(),
+                None::<&NoBody>,
+            )
+            .await;
+
+        Ok(user?.data)
+    }
+
+    /**
+    Fetch the orders a user has listed
+
+    # Arguments
+    - `name`: The users slug, usually their in-game name in lowercase
+
+    # Returns
+    A list of the users orders
+    */
+    pub async fn get_user_orders(&self, name: &str) -> Result<Vec<Order<Unowned>>, ApiError> {
+        let orders: Result<ApiResult<Vec<OrderItem>>, ApiError> = self
+            .call_api(
+                Method::Get,
+                format!("/orders/user/{}", name).as_str(),
+                None::<&NoBody>,
+            )
+            .await;
+
+        Ok(orders?.data.iter().map(Order::new).collect())
+    }
+
     /**
     Fetch all listed rivens from the WFM API
 
+    # Note
+    The result is cached in the same way as `get_items()`
+
     # Returns
     List of all listed rivens
     */
     pub async fn get_rivens(&self) -> Result<Vec<Riven>, ApiError> {
-        if !self.rivens_cache.is_empty() {
-            let mut new_items = Vec::new();
-            new_items.clone_from(&self.rivens_cache);
-            return Ok(new_items);
+        if let Some(rivens) = self.rivens_cache.read().unwrap().get() {
+            return Ok(rivens);
         }
 
+        self.fetch_rivens().await
+    }
+
+    /**
+    Fetch all listed rivens, optionally bypassing the cache
+
+    # Arguments
+    - `force`: Fetch from the API even if the cached list has not expired yet
+
+    # Returns
+    List of all listed rivens
+    */
+    pub async fn get_rivens_refresh(&self, force: bool) -> Result<Vec<Riven>, ApiError> {
+        if force {
+            self.fetch_rivens().await
+        } else {
+            self.get_rivens().await
+        }
+    }
+
+    /**
+    INTERNAL: Fetch the riven list from the API, bypassing and repopulating the cache
+    */
+    async fn fetch_rivens(&self) -> Result<Vec<Riven>, ApiError> {
         let rivens: Result<ApiResult<Vec<RivenObject>>, ApiError> = self
             .call_api(Method::Get, "/riven/weapons", None::<&NoBody>)
             .await;
 
-        Ok(rivens?.data.iter().map(|riven| Riven::new(riven)).collect())
+        let rivens: Vec<Riven> = rivens?.data.iter().map(Riven::new).collect();
+        self.rivens_cache.write().unwrap().set(rivens.clone());
+
+        Ok(rivens)
+    }
+
+    /**
+    Clear the cached riven list, the next call to `get_rivens()` will fetch from the API
+    */
+    pub fn invalidate_rivens_cache(&self) {
+        self.rivens_cache.write().unwrap().invalidate();
     }
 }
 
@@ -3,7 +3,7 @@ Provides a managed `Order` object, unlike the [`Order`][crate::types::item::Orde
 
 # Examples
 
-```rust
+```rust,no_run
 use wf_market::{
     client::Client,
     utils::generate_device_id,
@@ -17,25 +17,34 @@ async fn main() {
     };
 
     println!("My orders:");
-    client.orders.iter().map(|mut order| {
+    for order in &client.orders {
         let o = order.get_type();
         println!("{} (x{}): {}p", o.item_id, o.quantity, o.platinum);
-    })
+    }
 }
 ```
 */
 
-use crate::types::item::{Order as OrderItem, OrderType};
+use crate::client::item::{Item, Regular};
+use crate::client::{Authenticated, Client};
+use crate::error::ApiError;
+use crate::types::item::{Order as OrderItem, OrderType, OrderWithUser};
+use crate::types::request::OrderUpdateParams;
+use crate::types::statistics::ItemStatistics;
+use crate::types::transaction::Transaction;
+use crate::types::user::{MinimalUser, StatusType};
 use chrono::{DateTime, Utc};
 use std::marker::PhantomData;
 
+#[derive(Clone, Debug)]
 pub struct Owned;
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Unowned;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Order<State = Unowned> {
     pub(crate) object: OrderItem,
+    pub(crate) user: Option<MinimalUser>,
     _state: PhantomData<State>,
 }
 
@@ -49,11 +58,35 @@ impl<State> Order<State> {
     pub fn get_platinum(&self) -> u32 {
         self.object.platinum
     }
+    pub fn get_quantity(&self) -> u32 {
+        self.object.quantity
+    }
+    pub fn get_per_trade(&self) -> Option<u8> {
+        self.object.per_trade
+    }
+    pub fn get_subtype(&self) -> Option<&str> {
+        self.object.subtype.as_deref()
+    }
+    pub fn get_rank(&self) -> Option<u8> {
+        self.object.rank
+    }
+    pub fn get_charges(&self) -> Option<u8> {
+        self.object.charges
+    }
+    /// Platinum for the whole order (price × quantity), `per_trade` only splits it across trades
+    /// so does not change the total. Saturates at `u32::MAX`, see `total_value_u64`
+    pub fn total_value(&self) -> u32 {
+        self.object.platinum.saturating_mul(self.object.quantity)
+    }
+    /// Same as `total_value` without the risk of overflowing, for summing up large inventories
+    pub fn total_value_u64(&self) -> u64 {
+        u64::from(self.object.platinum) * u64::from(self.object.quantity)
+    }
     pub fn updated_at(&self) -> DateTime<Utc> {
-        self.object.updated_at.parse().unwrap_or_default()
+        self.object.updated_at
     }
     pub fn created_at(&self) -> DateTime<Utc> {
-        self.object.created_at.parse().unwrap_or_default()
+        self.object.created_at
     }
     pub fn get_visible(&self) -> bool {
         self.object.visible
@@ -61,12 +94,64 @@ impl<State> Order<State> {
     pub fn get_sell_type(&self) -> OrderType {
         self.object.order_type
     }
+    /// The user who placed the order, only known for orders fetched from item order listings
+    pub fn get_user(&self) -> Option<&MinimalUser> {
+        self.user.as_ref()
+    }
+
+    /**
+    The changes needed to turn this order into `other`, ready for [`Client::update_order`]
+
+    # Note
+    `per_trade` and `rank` can only be set, not removed, so they are left out when `other` has none
+
+    # Arguments
+    - `other`: The order as it should be
+
+    # Returns
+    An [`OrderUpdateParams`] with only the differing fields set, empty if nothing changed
+    */
+    pub fn diff<Other>(&self, other: &Order<Other>) -> OrderUpdateParams {
+        let (current, wanted) = (&self.object, &other.object);
+
+        OrderUpdateParams {
+            platinum: (current.platinum != wanted.platinum).then_some(wanted.platinum),
+            quantity: (current.quantity != wanted.quantity).then_some(wanted.quantity),
+            per_trade: wanted
+                .per_trade
+                .filter(|_| current.per_trade != wanted.per_trade)
+                .map(u32::from),
+            rank: wanted
+                .rank
+                .filter(|_| current.rank != wanted.rank)
+                .map(u32::from),
+            visible: (current.visible != wanted.visible).then_some(wanted.visible),
+        }
+    }
+}
+
+/// Orders are equal when the orders themselves are, regardless of the attached user
+impl<State> PartialEq for Order<State> {
+    fn eq(&self, other: &Self) -> bool {
+        self.object == other.object
+    }
 }
 
+impl<State> Eq for Order<State> {}
+
 impl Order<Unowned> {
-    pub(super) fn new(order: &OrderItem) -> Self {
+    pub(crate) fn new(order: &OrderItem) -> Self {
         Order {
             object: order.clone(),
+            user: None,
+            _state: PhantomData,
+        }
+    }
+
+    pub(crate) fn with_user(order: &OrderWithUser) -> Self {
+        Order {
+            object: order.order.clone(),
+            user: Some(order.user.clone()),
             _state: PhantomData,
         }
     }
@@ -76,7 +161,127 @@ impl Order<Owned> {
     pub(super) fn new_owned(order: &OrderItem) -> Self {
         Order {
             object: order.clone(),
+            user: None,
             _state: PhantomData,
         }
     }
+
+    /**
+    Delete this order, shorthand for [`Client::delete_order`]
+
+    # Arguments
+    - `client`: The client of the user owning the order
+
+    # Returns
+    The deleted order
+    */
+    pub async fn delete(self, client: &Client<Authenticated>) -> Result<Order, ApiError> {
+        client.delete_order(&self.object.id).await
+    }
+
+    /**
+    Close (part of) this order, shorthand for [`Client::close_order`]
+
+    # Arguments
+    - `client`: The client of the user owning the order
+    - `quantity`: The quantity to close
+
+    # Returns
+    The resulting transaction
+    */
+    pub async fn close(&self, client: &Client<Authenticated>, quantity: u32) -> Result<Transaction, ApiError> {
+        client.close_order(&self.object.id, quantity).await
+    }
+}
+
+/**
+The best orders of an item, as returned by the WFM top orders endpoint
+*/
+#[derive(Clone, Debug)]
+pub struct TopOrders {
+    /// Buy orders, highest price first
+    pub buy: Vec<Order<Unowned>>,
+    /// Sell orders, lowest price first
+    pub sell: Vec<Order<Unowned>>,
+}
+
+impl TopOrders {
+    /**
+    The cheapest visible sell order
+
+    # Arguments
+    - `online_only`: Only consider orders from users who are online or in game,
+      orders without user information are skipped
+
+    # Returns
+    - `None` if there are no matching sell orders
+    */
+    pub fn lowest_sell(&self, online_only: bool) -> Option<&Order<Unowned>> {
+        self.sell
+            .iter()
+            .filter(|o| is_competitive(o, online_only))
+            .min_by_key(|o| o.get_platinum())
+    }
+
+    /**
+    The highest paying visible buy order
+
+    # Arguments
+    - `online_only`: Only consider orders from users who are online or in game,
+      orders without user information are skipped
+
+    # Returns
+    - `None` if there are no matching buy orders
+    */
+    pub fn highest_buy(&self, online_only: bool) -> Option<&Order<Unowned>> {
+        self.buy
+            .iter()
+            .filter(|o| is_competitive(o, online_only))
+            .max_by_key(|o| o.get_platinum())
+    }
+
+    /// Price of [`lowest_sell`][TopOrders::lowest_sell]
+    pub fn lowest_sell_price(&self, online_only: bool) -> Option<u32> {
+        self.lowest_sell(online_only).map(|o| o.get_platinum())
+    }
+
+    /// Price of [`highest_buy`][TopOrders::highest_buy]
+    pub fn highest_buy_price(&self, online_only: bool) -> Option<u32> {
+        self.highest_buy(online_only).map(|o| o.get_platinum())
+    }
+}
+
+/**
+The live orders and trade statistics of an item, see
+[`Client::get_item_orders_with_stats`][crate::Client::get_item_orders_with_stats]
+*/
+#[derive(Clone, Debug)]
+pub struct OrdersWithStats {
+    pub top_orders: TopOrders,
+    pub stats: ItemStatistics,
+}
+
+fn is_competitive(order: &Order<Unowned>, online_only: bool) -> bool {
+    order.get_visible()
+        && (!online_only
+            || order.get_user().is_some_and(|u| {
+                matches!(u.status_type, StatusType::Online | StatusType::InGame)
+            }))
+}
+
+/**
+An order together with the context needed to act on it, see
+[`Client::enrich_orders`][crate::Client::enrich_orders]
+*/
+#[derive(Clone, Debug)]
+pub struct EnrichedOrder {
+    pub order: Order<Unowned>,
+    /// The ordered item, `None` if it is not in the item list
+    pub item: Option<Item<Regular>>,
+    /// Median price of trades closed within the last 48 hours
+    pub market_median: Option<f64>,
+    /// Whether the order beats the market median (cheaper sell or higher buy)
+    pub is_deal: bool,
+    /// Reputation of the user who placed the order, if known
+    pub seller_reputation: Option<i32>,
 }
@@ -0,0 +1,32 @@
+/**
+A single page of a list, see [`get_all_items_paged`][crate::Client::get_all_items_paged]
+*/
+#[derive(Clone, Debug)]
+pub struct Page<T> {
+    /// The entries on this page
+    pub items: Vec<T>,
+    /// Offset of the next page, `None` on the last page
+    pub next: Option<usize>,
+    /// Total amount of entries across all pages
+    pub total: usize,
+}
+
+impl<T> Page<T> {
+    /**
+    INTERNAL: Cut a page out of the full list
+
+    # Arguments
+    - `entries`: Every entry of the list
+    - `offset`: Index of the first entry on the page
+    - `limit`: Maximum amount of entries on the page
+    */
+    pub(super) fn slice(entries: Vec<T>, offset: usize, limit: usize) -> Self {
+        let total = entries.len();
+        let items: Vec<T> = entries.into_iter().skip(offset).take(limit).collect();
+
+        let end = offset.saturating_add(items.len());
+        let next = if end < total && !items.is_empty() { Some(end) } else { None };
+
+        Page { items, next, total }
+    }
+}
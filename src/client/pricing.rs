@@ -0,0 +1,106 @@
+use crate::client::order::{Order, Unowned};
+use crate::types::item::OrderType;
+use crate::types::statistics::ItemStatistics;
+
+/// Sample size (orders + traded volume) at which the confidence reaches 50%
+const CONFIDENCE_HALF_SAMPLE: f32 = 10.0;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PriceEstimate {
+    /// Estimated market price in platinum
+    pub price: f32,
+    /// How trustworthy the estimate is, from `0.0` to `1.0`
+    pub confidence: f32,
+    /// Lower end of the likely price range
+    pub low: f32,
+    /// Upper end of the likely price range
+    pub high: f32,
+}
+
+/**
+Estimate the market price of an item together with how much the estimate can be trusted
+
+The estimate is the median of the visible sell orders (after removing outliers using the
+1.5×IQR rule), blended with the median price of trades closed in the last 48 hours weighted
+by their volume. Confidence grows with the amount of orders and traded volume backing the price,
+and the `low`/`high` band widens as confidence drops.
+
+# Arguments
+- `orders`: Live orders of the item, buy orders are ignored
+- `stats`: Statistics of the item, if available
+
+# Returns
+- `None` if there are no sell orders and no recent trades to base a price on
+*/
+pub fn price_with_confidence(
+    orders: &[Order<Unowned>],
+    stats: Option<&ItemStatistics>,
+) -> Option<PriceEstimate> {
+    let mut prices: Vec<f32> = orders
+        .iter()
+        .filter(|o| o.get_visible() && o.get_sell_type() == OrderType::Sell)
+        .map(|o| o.get_platinum() as f32)
+        .collect();
+    prices.sort_by(f32::total_cmp);
+
+    let prices = remove_outliers(&prices);
+    let order_count = prices.len() as f32;
+
+    let (traded_volume, traded_median) = stats
+        .and_then(|s| s.closed.last_48_hours.last())
+        .map(|entry| (entry.volume as f32, entry.median as f32))
+        .unwrap_or((0.0, 0.0));
+
+    let sample = order_count + traded_volume;
+    if sample == 0.0 {
+        return None;
+    }
+
+    let price = (median(&prices).unwrap_or(0.0) * order_count + traded_median * traded_volume)
+        / sample;
+    let confidence = sample / (sample + CONFIDENCE_HALF_SAMPLE);
+
+    let spread = match (quantile(&prices, 0.25), quantile(&prices, 0.75)) {
+        (Some(q1), Some(q3)) => (q3 - q1) / 2.0,
+        _ => 0.0,
+    };
+    let band = spread + price * (1.0 - confidence) / 2.0;
+
+    Some(PriceEstimate {
+        price,
+        confidence,
+        low: (price - band).max(0.0),
+        high: price + band,
+    })
+}
+
+fn remove_outliers(sorted: &[f32]) -> Vec<f32> {
+    match (quantile(sorted, 0.25), quantile(sorted, 0.75)) {
+        (Some(q1), Some(q3)) if sorted.len() >= 4 => {
+            let fence = (q3 - q1) * 1.5;
+            sorted
+                .iter()
+                .copied()
+                .filter(|p| *p >= q1 - fence && *p <= q3 + fence)
+                .collect()
+        }
+        _ => sorted.to_vec(),
+    }
+}
+
+fn median(sorted: &[f32]) -> Option<f32> {
+    quantile(sorted, 0.5)
+}
+
+// Linear interpolation between the closest ranks
+fn quantile(sorted: &[f32], q: f32) -> Option<f32> {
+    if sorted.is_empty() {
+        return None;
+    }
+
+    let pos = (sorted.len() - 1) as f32 * q;
+    let lower = sorted[pos.floor() as usize];
+    let upper = sorted[pos.ceil() as usize];
+
+    Some(lower + (upper - lower) * pos.fract())
+}
@@ -3,18 +3,15 @@ Provides a managed `Riven` object, unlike the [`Riven`][crate::types::riven::Riv
 
 # Examples
 
-```rust
-use wf_market::{
-    client::Client,
-    utils::generate_device_id,
-};
+```rust,no_run
+use wf_market::client::Client;
 
 #[tokio::main]
 async fn main() {
-    let mut client = Client::new();
+    let client = Client::new();
 
     match client.get_rivens().await {
-        Ok(mut rivens) => {
+        Ok(rivens) => {
             println!("Riven Slugs:");
             for riven in rivens {
                 println!("{}: {}", riven.get_slug(), riven.get_name());
@@ -26,9 +23,10 @@ async fn main() {
 ```
 */
 
+use super::constants::DEFAULT_LANGUAGE;
 use crate::types::riven::Riven as RiveType;
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Riven {
     object: RiveType,
 }
@@ -54,4 +52,13 @@ impl Riven {
             String::new()
         }
     }
+
+    /// The wiki page of the weapon in the given language (e.g. `de`), falling back to english
+    pub fn get_wiki_link(&self, lang: &str) -> Option<String> {
+        self.object
+            .i18n
+            .get(lang)
+            .or_else(|| self.object.i18n.get(DEFAULT_LANGUAGE))
+            .and_then(|translation| translation.wiki_link.clone())
+    }
 }
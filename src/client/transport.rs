@@ -0,0 +1,83 @@
+use crate::error::ApiError;
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use std::future::Future;
+use std::pin::Pin;
+
+/**
+A response as seen by the client, the body is read in full by the transport
+*/
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+/**
+Sends the requests of a [`Client`][crate::Client], see [`with_transport`][crate::Client::with_transport]
+
+The default transport is the clients `reqwest::Client`, implement this trait to e.g. answer
+requests with canned responses in tests
+
+# Examples
+
+```rust
+use std::future::Future;
+use std::pin::Pin;
+use wf_market::client::{Client, HttpResponse, HttpTransport};
+use wf_market::error::ApiError;
+
+struct NotFound;
+
+impl HttpTransport for NotFound {
+    fn send(
+        &self,
+        _request: reqwest::Request,
+    ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, ApiError>> + Send + '_>> {
+        Box::pin(async {
+            Ok(HttpResponse {
+                status: reqwest::StatusCode::NOT_FOUND,
+                headers: reqwest::header::HeaderMap::new(),
+                body: String::new(),
+            })
+        })
+    }
+}
+
+let client = Client::new().with_transport(NotFound);
+```
+*/
+pub trait HttpTransport: Send + Sync {
+    /**
+    Send a request and read its response
+
+    # Arguments
+    - `request`: The request, with the headers WFM expects already applied
+
+    # Returns
+    - The response, whatever its status
+    - `ApiError::Request` if the request could not be sent, connection errors and timeouts are retried
+    */
+    fn send(
+        &self,
+        request: reqwest::Request,
+    ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, ApiError>> + Send + '_>>;
+}
+
+impl HttpTransport for reqwest::Client {
+    fn send(
+        &self,
+        request: reqwest::Request,
+    ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, ApiError>> + Send + '_>> {
+        Box::pin(async move {
+            let resp = self.execute(request).await?;
+
+            Ok(HttpResponse {
+                status: resp.status(),
+                headers: resp.headers().clone(),
+                body: resp.text().await?,
+            })
+        })
+    }
+}
@@ -1,36 +1,79 @@
+use super::constants::{
+    DEFAULT_CONNECT_TIMEOUT, DEFAULT_TIMEOUT, RETRY_BASE_DELAY, RETRY_MAX_DELAY,
+};
 use crate::types::user::FullUser;
+use chrono::{DateTime, Utc};
 use governor::clock::DefaultClock;
 use governor::state::{InMemoryState, NotKeyed};
 use governor::{Quota, RateLimiter};
+use rand::Rng;
+use reqwest::{RequestBuilder, StatusCode};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
 use serde::Deserialize;
 use std::num::NonZeroU32;
+use std::time::Duration;
 
 #[derive(Deserialize)]
 pub(super) struct AuthResp {
-    pub(super) user: FullUser,
+    #[serde(default)]
+    pub(super) user: Option<FullUser>,
+}
+
+/**
+INTERNAL: Settings used when building the HTTP client, kept around to rebuild it when changed
+*/
+#[derive(Clone)]
+pub(super) struct HttpConfig {
+    pub(super) timeout: Duration,
+    pub(super) connect_timeout: Duration,
+    pub(super) proxy: Option<reqwest::Proxy>,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        HttpConfig {
+            timeout: DEFAULT_TIMEOUT,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            proxy: None,
+        }
+    }
 }
 
 /**
 INTERNAL: Build the HTTP client with default settings
 
 # Arguments
-- `auth`: Authentication token used when communicating with authenticated endpoints
+- `config`: Timeouts and proxy to apply to the client
 
 # Returns
-- A `reqwest::Client` with assigned default headers
+- A `reqwest::Client`, headers are added per request (see [`with_headers`])
 */
-pub(super) fn build_http(auth: Option<String>) -> reqwest::Client {
-    let mut headers = reqwest::header::HeaderMap::new();
-    if let Some(auth) = auth {
-        headers.insert(reqwest::header::AUTHORIZATION, auth.parse().unwrap());
+pub(super) fn build_http(config: &HttpConfig) -> reqwest::Client {
+    let mut builder = reqwest::Client::builder()
+        .timeout(config.timeout)
+        .connect_timeout(config.connect_timeout);
+
+    if let Some(proxy) = &config.proxy {
+        builder = builder.proxy(proxy.clone());
     }
-    headers.insert("language", "en".parse().unwrap());
-    headers.insert("platform", "pc".parse().unwrap());
 
-    reqwest::Client::builder()
-        .default_headers(headers)
-        .build()
-        .unwrap()
+    builder.build().unwrap()
+}
+
+/**
+INTERNAL: Add the headers WFM expects on every request, so user provided clients work as well
+
+# Arguments
+- `builder`: The request to add the headers to
+- `token`: Authentication token used when communicating with authenticated endpoints
+*/
+pub(super) fn with_headers(builder: RequestBuilder, token: Option<&str>) -> RequestBuilder {
+    let builder = builder.header("language", "en").header("platform", "pc");
+
+    match token {
+        Some(token) => builder.bearer_auth(token),
+        None => builder,
+    }
 }
 
 /**
@@ -39,3 +82,48 @@ INTERNAL: Build the rate limiter for throttling outgoing requests to max allowed
 pub(super) fn build_limiter(rps: NonZeroU32) -> RateLimiter<NotKeyed, InMemoryState, DefaultClock> {
     RateLimiter::direct(Quota::per_second(rps))
 }
+
+/**
+INTERNAL: Whether a response status is worth retrying (rate limited or temporarily unavailable)
+*/
+pub(super) fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::SERVICE_UNAVAILABLE
+}
+
+/**
+INTERNAL: Read the `Retry-After` header, supporting both the delay-seconds and HTTP-date forms
+
+# Returns
+- The amount of time the server asked us to wait, if any
+*/
+pub(super) fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?.with_timezone(&Utc);
+    (date - Utc::now()).to_std().ok()
+}
+
+/**
+INTERNAL: Compute how long to wait before the next retry
+
+# Arguments
+- `attempt`: Zero-based index of the attempt that just failed
+- `retry_after`: Delay requested by the server, takes precedence over the computed backoff
+
+# Returns
+- Exponential backoff with random jitter, capped at `RETRY_MAX_DELAY`
+*/
+pub(super) fn backoff_delay(attempt: u32, retry_after: Option<Duration>) -> Duration {
+    if let Some(retry_after) = retry_after {
+        return retry_after.min(RETRY_MAX_DELAY);
+    }
+
+    let exponential = RETRY_BASE_DELAY.saturating_mul(2u32.saturating_pow(attempt));
+    let jitter = rand::rng().random_range(0..=RETRY_BASE_DELAY.as_millis() as u64);
+
+    (exponential + Duration::from_millis(jitter)).min(RETRY_MAX_DELAY)
+}
@@ -6,52 +6,65 @@ Build a WebSocket Client to receive real-time information from Warframe Market
 To build a new WsClient, please see the [`Client`][crate::Client] documentation
 
 ## Record active users
-```rust
+```rust,no_run
 use wf_market::{
     error::WsError,
-    client::ws::WsClient,
+    client::ws::{OnlineCount, WsClient},
     Client,
 };
 
 #[tokio::main]
 async fn main() -> Result<(), WsError> {
-    let mut client = {
+    let client = {
         Client::new()
             .login("user", "pass", "dev").await.unwrap()
     };
 
-    let client = client.create_websocket()
+    let ws_client: WsClient = client.create_websocket()
         .register_callback("event/reports/online", |msg, _, _| {
-            let payload = msg.payload.clone().unwrap();
-            println!("Users Online: {}", payload.get("authorizedUsers").unwrap().as_i64());
+            let count: OnlineCount = msg.parse_payload()?;
+            println!("Users Online: {}", count.authorized_users);
             Ok(())
         })?
         .build().await?;
 
-    tokio::signal::ctrl_c().await.unwrap() // Our client is not long-running, so to keep the WsClient in scope we need to never return
+    tokio::signal::ctrl_c().await.unwrap(); // Keep the WsClient in scope until we are asked to stop
+    ws_client.close().await;
+    Ok(())
 }
 ```
 Note:
--- Use internal/connected and internal/disconnected to handle connection state
+-- Use `on_connection_change` (or the internal/connected and internal/disconnected routes) to handle connection state
+-- Connection problems are reported through the [`log`](https://docs.rs/log) crate, install a logger to see them
 */
 
+use crate::client::order::{Order, Unowned};
 use crate::error::WsError;
+use crate::types::item::OrderWithUser;
+use crate::types::user::StatusType;
 use futures_util::stream::{AbortHandle, Abortable};
 use futures_util::{SinkExt, StreamExt};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, oneshot, watch};
+use tokio::task::JoinHandle;
 use tokio_tungstenite::connect_async;
 use tokio_tungstenite::tungstenite::client::IntoClientRequest;
-use tokio_tungstenite::tungstenite::{Message, Utf8Bytes};
+use tokio_tungstenite::tungstenite::{Error as TungsteniteError, Message, Utf8Bytes};
 
-pub(super) const WS_URL: &'static str = "wss://warframe.market/socket-v2";
+pub(super) const WS_URL: &str = "wss://warframe.market/socket-v2";
+
+// Subscribing to the orders of an item and the event delivering them
+const SUBSCRIBE_ITEM_ROUTE: &str = "@wfm|cmd/subscribe/newOrders";
+const NEW_ORDER_PATH: &str = "event/subscriptions/newOrder";
+const PRIVATE_MESSAGE_ROUTE: &str = "@wfm|cmd/chats/sendMessage";
 
 // Uncomment for local testing
-// pub(super) const WS_URL: &'static str = "ws://localhost:7369";
+// pub(super) const WS_URL: &str = "ws://localhost:7369";
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct WsMessage {
@@ -72,6 +85,18 @@ impl WsMessage {
             ref_id: None,
         }
     }
+    /**
+    Deserialize the payload into the given type, a missing payload is treated as `null`
+
+    # Returns
+    - The deserialized payload
+    - `WsError::InvalidPayload` if the payload does not match the type
+    */
+    pub fn parse_payload<T: DeserializeOwned>(&self) -> Result<T, WsError> {
+        let payload = self.payload.clone().unwrap_or_default();
+        serde_json::from_value(payload)
+            .map_err(|e| WsError::InvalidPayload(format!("{} on route {}", e, self.route)))
+    }
     pub fn connect() -> Self {
         WsMessage {
             route: "@internal|internal/connected".to_string(),
@@ -90,6 +115,14 @@ impl WsMessage {
     }
 }
 
+// Payload of the `event/reports/online` route
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct OnlineCount {
+    pub connections: u32,
+    pub authorized_users: u32,
+}
+
 // Route structure with parameter support
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Route {
@@ -126,13 +159,6 @@ impl Route {
         }
     }
 
-    pub fn to_string(&self) -> String {
-        match &self.parameter {
-            Some(param) => format!("{}|{}:{}", self.protocol, self.path, param),
-            None => format!("{}|{}", self.protocol, self.path),
-        }
-    }
-
     // Get the base path without parameter for routing
     pub fn base_path(&self) -> &str {
         &self.path
@@ -145,6 +171,24 @@ impl Route {
             None => self.path.clone(),
         }
     }
+
+    // Whether a registered path matches, either exactly (with or without parameter)
+    // or as a prefix pattern ending in `*` (e.g. "cmd/subscribe/*")
+    pub fn matches(&self, pattern: &str) -> bool {
+        match pattern.strip_suffix('*') {
+            Some(prefix) => self.full_path().starts_with(prefix),
+            None => pattern == self.full_path() || pattern == self.base_path(),
+        }
+    }
+}
+
+impl std::fmt::Display for Route {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.parameter {
+            Some(param) => write!(f, "{}|{}:{}", self.protocol, self.path, param),
+            None => write!(f, "{}|{}", self.protocol, self.path),
+        }
+    }
 }
 
 // Message sender handle that can be cloned and passed to callbacks
@@ -154,10 +198,11 @@ pub struct MessageSender {
 }
 
 impl MessageSender {
+    /// Queue a message, fails with `WsError::NotConnected` once the connection it belongs to is gone
     pub fn send_message(&self, message: WsMessage) -> Result<(), WsError> {
         self.tx
             .send(message)
-            .map_err(|e| WsError::SendError(e.to_string()))?;
+            .map_err(|_| WsError::NotConnected)?;
         Ok(())
     }
 
@@ -189,19 +234,74 @@ impl MessageSender {
     }
 }
 
+// Waiters for responses to requests sent through `WsClient::request`, keyed by request id
+pub(crate) type PendingRequests =
+    Arc<Mutex<HashMap<String, oneshot::Sender<Result<WsMessage, WsError>>>>>;
+
 // Updated callback type to include sender and route info
 pub type MessageCallback =
     Arc<dyn Fn(&WsMessage, &Route, &MessageSender) -> Result<(), WsError> + Send + Sync>;
 
+// Lifecycle of the connection, see `WsClientBuilder::on_connection_change`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionState {
+    Connecting,
+    Connected,
+    Disconnected(String),
+    Reconnecting,
+}
+
+pub type ConnectionCallback = Arc<dyn Fn(ConnectionState) + Send + Sync>;
+
+// Invoked for messages no callback is registered for, see `WsClientBuilder::on_unhandled`
+pub type UnhandledCallback = Arc<dyn Fn(&WsMessage, &Route) + Send + Sync>;
+
 // Internal router
 pub(crate) struct Router {
     routes: HashMap<String, MessageCallback>,
+    connection_callback: Option<ConnectionCallback>,
+    unhandled_callback: Option<UnhandledCallback>,
+    state: Mutex<ConnectionState>,
 }
 
 impl Router {
     fn new() -> Self {
         Self {
             routes: HashMap::new(),
+            connection_callback: None,
+            unhandled_callback: None,
+            state: Mutex::new(ConnectionState::Connecting),
+        }
+    }
+
+    fn notify_unhandled(&self, message: &WsMessage, route: &Route) {
+        log::debug!("No handler for route: {}", route);
+        if let Some(callback) = &self.unhandled_callback {
+            callback(message, route);
+        }
+    }
+
+    fn notify_connection(&self, state: ConnectionState) {
+        *self.state.lock().unwrap() = state.clone();
+        if let Some(callback) = &self.connection_callback {
+            callback(state);
+        }
+    }
+
+    // Map the internal connect/disconnect messages to a `ConnectionState`
+    fn notify_internal_message(&self, route: &Route, message: &WsMessage) {
+        match route.base_path() {
+            "internal/connected" => self.notify_connection(ConnectionState::Connected),
+            "internal/disconnected" => {
+                let reason = message
+                    .payload
+                    .as_ref()
+                    .and_then(|payload| payload.get("reason"))
+                    .and_then(|reason| reason.as_str())
+                    .unwrap_or_default();
+                self.notify_connection(ConnectionState::Disconnected(reason.to_string()))
+            }
+            _ => {}
         }
     }
 
@@ -232,6 +332,10 @@ impl Router {
     fn route_message(&self, message: &WsMessage, sender: &MessageSender) -> Result<(), WsError> {
         let route = Route::parse(&message.route)?;
 
+        if route.protocol == "@internal" {
+            self.notify_internal_message(&route, message);
+        }
+
         // Handle internal routes first
         if Self::is_path_reserved(route.base_path()) {
             self.handle_internal_route(&route, message, sender)?;
@@ -241,21 +345,24 @@ impl Router {
         // Try to find callback with routing priority:
         // 1. Exact match with parameter (e.g., "cmd/subscribe/newOrders:ok")
         // 2. Base path match (e.g., "cmd/subscribe/newOrders")
+        // 3. Longest matching prefix pattern (e.g., "cmd/subscribe/*")
 
         let callback = self
             .routes
             .get(&route.full_path())
-            .or_else(|| self.routes.get(route.base_path()));
+            .or_else(|| self.routes.get(route.base_path()))
+            .or_else(|| {
+                self.routes
+                    .iter()
+                    .filter(|(pattern, _)| pattern.ends_with('*') && route.matches(pattern))
+                    .max_by_key(|(pattern, _)| pattern.len())
+                    .map(|(_, callback)| callback)
+            });
 
         if let Some(callback) = callback {
             callback(message, &route, sender)?;
         } else {
-            // Optionally log unhandled routes
-            println!(
-                "No handler for route: {} (full: {})",
-                route.base_path(),
-                route.full_path()
-            );
+            self.notify_unhandled(message, &route);
         }
 
         Ok(())
@@ -265,15 +372,11 @@ impl Router {
     fn handle_internal_route(
         &self,
         route: &Route,
-        _message: &WsMessage,
+        message: &WsMessage,
         sender: &MessageSender,
     ) -> Result<(), WsError> {
         match route.base_path() {
             "cmd/auth/signIn" => {
-                println!(
-                    "Handling internal auth sign in with parameter: {:?}",
-                    route.parameter
-                );
                 // Example: Handle different auth responses based on parameter
                 match route.parameter.as_deref() {
                     Some("ok") => {
@@ -292,21 +395,14 @@ impl Router {
                                     ref_id: None,
                                 },
                                 &route,
-                                &sender,
+                                sender,
                             )?;
                         }
                     }
-                    Some("error") => println!("Auth failed"),
-                    _ => println!("Unknown auth response"),
+                    _ => self.notify_unhandled(message, route),
                 }
             }
-            _ => {
-                println!(
-                    "Unhandled internal route: {} (parameter: {:?})",
-                    route.base_path(),
-                    route.parameter
-                );
-            }
+            _ => self.notify_unhandled(message, route),
         }
         Ok(())
     }
@@ -317,6 +413,56 @@ pub struct WsClientBuilder {
     router: Router,
     token: String,
     device_id: String,
+    reconnect: ReconnectPolicy,
+    request_timeout: Duration,
+    connect_timeout: Duration,
+    heartbeat: Option<Heartbeat>,
+    url: String,
+}
+
+// How often to ping the server and how long to wait for it to answer
+#[derive(Clone, Copy)]
+struct Heartbeat {
+    interval: Duration,
+    timeout: Duration,
+}
+
+// How long to wait between reconnects and when to give up
+#[derive(Clone, Copy)]
+struct ReconnectPolicy {
+    initial: Duration,
+    max: Duration,
+    max_attempts: Option<u32>,
+}
+
+impl ReconnectPolicy {
+    // Delay before the given (zero based) reconnect attempt, `None` once out of attempts
+    fn delay(&self, attempt: u32) -> Option<Duration> {
+        if self.max_attempts.is_some_and(|max| attempt >= max) {
+            return None;
+        }
+
+        let delay = self
+            .initial
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max);
+
+        // Only jitter when backing off, a fixed delay stays fixed
+        if self.initial >= self.max {
+            return Some(delay);
+        }
+        Some(delay.mul_f64(rand::random_range(0.5..=1.0)))
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        ReconnectPolicy {
+            initial: Duration::from_secs(5),
+            max: Duration::from_secs(5),
+            max_attempts: None,
+        }
+    }
 }
 
 impl WsClientBuilder {
@@ -325,14 +471,76 @@ impl WsClientBuilder {
             router: Router::new(),
             token,
             device_id,
+            reconnect: ReconnectPolicy::default(),
+            request_timeout: Duration::from_secs(10),
+            connect_timeout: Duration::from_secs(30),
+            heartbeat: Some(Heartbeat {
+                interval: Duration::from_secs(30),
+                timeout: Duration::from_secs(10),
+            }),
+            url: WS_URL.to_string(),
         }
     }
 
+    /// Ping the server every `interval`, reconnecting if nothing is received within `timeout` after a ping
+    ///
+    /// Keeps idle connections from being dropped silently (default: every 30 seconds, 10 second timeout)
+    pub fn with_heartbeat(mut self, interval: Duration, timeout: Duration) -> Self {
+        self.heartbeat = Some(Heartbeat { interval, timeout });
+        self
+    }
+
+    /// Don't ping the server, an idle connection is only noticed to be gone once sending fails
+    pub fn without_heartbeat(mut self) -> Self {
+        self.heartbeat = None;
+        self
+    }
+
+    /// How long `WsClient::request` waits for a response before failing with `WsError::Timeout` (default: 10 seconds)
+    pub fn with_request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// How long `build` waits for the first connection before failing with `WsError::Timeout` (default: 30 seconds)
+    ///
+    /// Reconnects are retried within this time, see `with_max_reconnect_attempts`
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    // Connect to another server, e.g. a local one in tests
+    #[cfg(test)]
+    pub(crate) fn with_url(mut self, url: &str) -> Self {
+        self.url = url.to_string();
+        self
+    }
+
+    /// Reconnect with an exponential, jittered backoff starting at `initial` and capped at `max`
+    ///
+    /// Defaults to a fixed delay of 5 seconds, passing the same value twice keeps the delay fixed
+    pub fn with_reconnect_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.reconnect.initial = initial;
+        self.reconnect.max = max.max(initial);
+        self
+    }
+
+    /// Give up after `max` consecutive failed reconnects, `None` keeps retrying forever (default)
+    ///
+    /// Once given up, a final `internal/disconnected` message is emitted
+    pub fn with_max_reconnect_attempts(mut self, max: Option<u32>) -> Self {
+        self.reconnect.max_attempts = max;
+        self
+    }
+
     /// Register a callback for a specific path with optional parameter
     ///
     /// Examples:
     /// - `register_callback("cmd/subscribe/newOrders", callback)` - matches any parameter
     /// - `register_callback("cmd/subscribe/newOrders:ok", callback)` - matches only :ok parameter
+    /// - `register_callback("cmd/subscribe/*", callback)` - matches every route starting with `cmd/subscribe/`,
+    ///   used only when no exact match is registered
     pub fn register_callback<F>(mut self, path: &str, callback: F) -> Result<Self, WsError>
     where
         F: Fn(&WsMessage, &Route, &MessageSender) -> Result<(), WsError> + Send + Sync + 'static,
@@ -341,37 +549,102 @@ impl WsClientBuilder {
         Ok(self)
     }
 
+    /// Register a callback receiving the new orders of items subscribed to with `WsClient::subscribe_item`
+    ///
+    /// Takes up the `event/subscriptions/newOrder` route, payloads which are not an order fail with
+    /// `WsError::InvalidPayload`
+    pub fn on_new_order<F>(self, callback: F) -> Result<Self, WsError>
+    where
+        F: Fn(Order<Unowned>, &MessageSender) -> Result<(), WsError> + Send + Sync + 'static,
+    {
+        self.register_callback(NEW_ORDER_PATH, move |msg, _, sender| {
+            let order: OrderWithUser = msg.parse_payload()?;
+            callback(Order::with_user(&order), sender)
+        })
+    }
+
+    /// Register a callback invoked whenever the connection state changes
+    ///
+    /// A typed alternative to the `internal/connected` and `internal/disconnected` routes,
+    /// which keep working alongside it
+    pub fn on_connection_change<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(ConnectionState) + Send + Sync + 'static,
+    {
+        self.router.connection_callback = Some(Arc::new(callback));
+        self
+    }
+
+    /// Register a callback invoked for every message without a matching callback, e.g. to log them
+    ///
+    /// This includes failed sign ins (`cmd/auth/signIn:error`), otherwise they are only logged at debug level
+    pub fn on_unhandled<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&WsMessage, &Route) + Send + Sync + 'static,
+    {
+        self.router.unhandled_callback = Some(Arc::new(callback));
+        self
+    }
+
     /// Get list of paths reserved by the client for internal usage
     pub fn get_reserved_paths() -> Vec<&'static str> {
         Router::get_reserved_paths()
     }
 
     /// Build and start the WebSocket client
+    ///
+    /// Resolves once connected and the sign in has been sent, which waits through reconnects.
+    /// Fails with `WsError::ConnectionError` if the client gives up before ever connecting,
+    /// see `with_max_reconnect_attempts`, and with `WsError::Timeout` if no connection was made
+    /// within the connect timeout, see `with_connect_timeout`
     pub async fn build(self) -> Result<WsClient, WsError> {
         let router = Arc::new(self.router);
         let sender_holder = Arc::new(Mutex::new(None));
+        let pending: PendingRequests = Arc::new(Mutex::new(HashMap::new()));
+        let (shutdown, shutdown_rx) = watch::channel(false);
+        let (ready_tx, ready_rx) = oneshot::channel::<()>();
 
-        tokio::spawn({
+        let task = tokio::spawn({
             let sender_holder = Arc::clone(&sender_holder);
             let router = Arc::clone(&router);
+            let pending = Arc::clone(&pending);
+            let mut shutdown_rx = shutdown_rx;
+            let reconnect = self.reconnect;
+            let heartbeat = self.heartbeat;
+            let url = self.url;
+            let mut attempt: u32 = 0;
+            let mut ready_tx = Some(ready_tx);
 
             async move {
-                loop {
-                    let mut request = WS_URL.into_client_request().unwrap();
+                while !*shutdown_rx.borrow() {
+                    let mut request = url.as_str().into_client_request().unwrap();
                     let headers = request.headers_mut();
                     headers.append("Sec-WebSocket-Protocol", "wfm".parse().unwrap());
                     headers.append("User-Agent", "wf-market-rs".parse().unwrap());
 
-                    // println!("Attempting to connect to WebSocket...");
+                    log::debug!("Attempting to connect to WebSocket...");
+                    router.notify_connection(if attempt == 0 {
+                        ConnectionState::Connecting
+                    } else {
+                        ConnectionState::Reconnecting
+                    });
 
-                    match connect_async(request).await {
+                    let connection = tokio::select! {
+                        connection = connect_async(request) => connection,
+                        _ = wait_for_shutdown(&mut shutdown_rx) => break,
+                    };
+
+                    match connection {
                         Ok((ws_stream, _)) => {
-                            // println!("Connected to WebSocket.");
+                            log::info!("Connected to WebSocket.");
+                            attempt = 0;
                             let ws_error = Arc::new(Mutex::new(None));
                             let ws_error_write = Arc::clone(&ws_error);
                             let ws_error_read = Arc::clone(&ws_error);
                             let (mut write, read) = ws_stream.split();
                             let (tx, mut rx) = mpsc::unbounded_channel::<WsMessage>();
+                            // Control frames (pongs) the read task needs written
+                            let (control_tx, mut control_rx) = mpsc::unbounded_channel::<Message>();
                             let sender = MessageSender { tx: tx.clone() };
 
                             // Send connection message to the router
@@ -385,12 +658,15 @@ impl WsClientBuilder {
                             match sender.send_request("@wfm|cmd/auth/signIn", auth_payload) {
                                 Ok(_) => {}
                                 Err(e) => {
-                                    eprintln!("Failed to send authentication request: {:?}", e);
+                                    log::error!("Failed to send authentication request: {:?}", e);
                                     continue; // Retry connection
                                 }
                             }
 
                             *sender_holder.lock().unwrap() = Some(sender.clone());
+                            if let Some(ready_tx) = ready_tx.take() {
+                                let _ = ready_tx.send(());
+                            }
 
                             // Create an abort handle to control the write task
                             let (abort_handle, abort_registration) = AbortHandle::new_pair();
@@ -398,15 +674,50 @@ impl WsClientBuilder {
                             // Write task (wrapped in Abortable) Is responsible for sending messages
                             // It will be aborted if the read task fails or ends
                             let write_task = tokio::spawn(Abortable::new(
-                                async move {
-                                    let ws_error_write = Arc::clone(&ws_error_write);
-                                    while let Some(msg) = rx.recv().await {
-                                        if let Ok(json) = serde_json::to_string(&msg) {
-                                            if let Err(e) = write
-                                                .send(Message::Text(Utf8Bytes::from(json)))
-                                                .await
+                                {
+                                    let mut shutdown_rx = shutdown_rx.clone();
+
+                                    async move {
+                                        let ws_error_write = Arc::clone(&ws_error_write);
+                                        let mut pings = heartbeat.map(|heartbeat| {
+                                            tokio::time::interval_at(
+                                                tokio::time::Instant::now() + heartbeat.interval,
+                                                heartbeat.interval,
+                                            )
+                                        });
+                                        loop {
+                                            let msg = tokio::select! {
+                                                msg = rx.recv() => msg,
+                                                Some(frame) = control_rx.recv() => {
+                                                    if let Err(e) = write.send(frame).await {
+                                                        log::warn!("Write failed: {}", e);
+                                                        *ws_error_write.lock().unwrap() = Some(e);
+                                                        break;
+                                                    }
+                                                    continue;
+                                                }
+                                                _ = next_ping(&mut pings) => {
+                                                    if let Err(e) = write.send(Message::Ping(Default::default())).await {
+                                                        log::warn!("Ping failed: {}", e);
+                                                        *ws_error_write.lock().unwrap() = Some(e);
+                                                        break;
+                                                    }
+                                                    continue;
+                                                }
+                                                _ = wait_for_shutdown(&mut shutdown_rx) => {
+                                                    // Let the server know we are leaving
+                                                    let _ = write.close().await;
+                                                    break;
+                                                }
+                                            };
+                                            let Some(msg) = msg else { break };
+
+                                            if let Ok(json) = serde_json::to_string(&msg)
+                                                && let Err(e) = write
+                                                    .send(Message::Text(Utf8Bytes::from(json)))
+                                                    .await
                                             {
-                                                eprintln!("Write failed: {}", e);
+                                                log::warn!("Write failed: {}", e);
                                                 *ws_error_write.lock().unwrap() = Some(e);
                                                 break;
                                             }
@@ -420,27 +731,51 @@ impl WsClientBuilder {
                             let read_task = tokio::spawn({
                                 let sender = sender.clone();
                                 let router = Arc::clone(&router);
+                                let pending = Arc::clone(&pending);
                                 let abort_handle = abort_handle.clone(); // Move handle in
                                 let mut read = read;
+                                let mut shutdown_rx = shutdown_rx.clone();
 
                                 async move {
                                     let ws_error_read = Arc::clone(&ws_error_read);
-                                    while let Some(msg) = read.next().await {
+                                    // Any frame (usually the pong) shows the connection is alive
+                                    let silence_limit = heartbeat.map(|heartbeat| heartbeat.interval + heartbeat.timeout);
+                                    loop {
+                                        let msg = tokio::select! {
+                                            msg = read.next() => msg,
+                                            _ = wait_for_silence(silence_limit) => {
+                                                log::warn!("No response to heartbeat, reconnecting");
+                                                *ws_error_read.lock().unwrap() = Some(TungsteniteError::Io(
+                                                    std::io::Error::new(std::io::ErrorKind::TimedOut, "heartbeat timed out"),
+                                                ));
+                                                break;
+                                            }
+                                            _ = wait_for_shutdown(&mut shutdown_rx) => break,
+                                        };
+                                        let Some(msg) = msg else { break };
+
                                         match msg {
                                             Ok(Message::Text(text)) => {
                                                 if let Err(e) = WsClient::handle_text_message(
-                                                    &router, &text, &sender,
+                                                    &router, &pending, &text, &sender,
                                                 ) {
-                                                    eprintln!("Handle error: {:?}", e);
+                                                    log::warn!("Handle error: {:?}", e);
                                                 }
                                             }
                                             Ok(Message::Close(_)) => {
-                                                println!("Connection closed by server.");
+                                                log::info!("Connection closed by server.");
                                                 break;
                                             }
-                                            Ok(_) => (),
+                                            Ok(Message::Ping(data)) => {
+                                                let _ = control_tx.send(Message::Pong(data));
+                                            }
+                                            Ok(Message::Pong(_)) => log::trace!("Received pong"),
+                                            Ok(Message::Binary(data)) => {
+                                                log::warn!("Ignoring unexpected binary message ({} bytes)", data.len());
+                                            }
+                                            Ok(Message::Frame(_)) => (),
                                             Err(e) => {
-                                                eprintln!("Read error: {}", e);
+                                                log::warn!("Read error: {}", e);
                                                 *ws_error_read.lock().unwrap() = Some(e);
                                                 break;
                                             }
@@ -454,39 +789,134 @@ impl WsClientBuilder {
 
                             // Wait for both tasks
                             let _ = tokio::join!(read_task, write_task);
+                            // Nothing sent from here on would reach the server
+                            *sender_holder.lock().unwrap() = None;
+                            // Responses to in-flight requests will never arrive on a new connection
+                            for (_, waiter) in pending.lock().unwrap().drain() {
+                                let _ = waiter.send(Err(WsError::ConnectionError));
+                            }
+                            if *shutdown_rx.borrow() {
+                                WsClient::send_disconnect_message(
+                                    &router,
+                                    &WsMessage::disconnect("Client closed".to_string()),
+                                    &sender,
+                                )
+                                .unwrap();
+                                break;
+                            }
+                            let delay = reconnect.delay(attempt);
+                            attempt += 1;
+
                             // Send a message to the sender to indicate disconnection
+                            let reason = match delay {
+                                Some(delay) => format!(
+                                    "Connection lost: {:?} will retry in {:?}",
+                                    ws_error.lock().unwrap(),
+                                    delay
+                                ),
+                                None => format!(
+                                    "Connection lost: {:?} giving up",
+                                    ws_error.lock().unwrap()
+                                ),
+                            };
                             WsClient::send_disconnect_message(
                                 &router,
-                                &WsMessage::disconnect(format!(
-                                    "Connection lost: {:?} will retry in 5 seconds",
-                                    ws_error.lock().unwrap()
-                                )),
+                                &WsMessage::disconnect(reason),
                                 &sender,
                             )
                             .unwrap();
-                            tokio::time::sleep(Duration::from_secs(5)).await;
+
+                            let Some(delay) = delay else { break };
+                            tokio::select! {
+                                _ = tokio::time::sleep(delay) => {}
+                                _ = wait_for_shutdown(&mut shutdown_rx) => break,
+                            }
                         }
 
                         Err(err) => {
-                            eprintln!("WebSocket connection failed: {}", err);
-                            tokio::time::sleep(Duration::from_secs(5)).await;
+                            log::warn!("WebSocket connection failed: {}", err);
+
+                            let Some(delay) = reconnect.delay(attempt) else {
+                                // Nobody to talk to anymore, hand the router a detached sender
+                                let (tx, _) = mpsc::unbounded_channel();
+                                WsClient::send_disconnect_message(
+                                    &router,
+                                    &WsMessage::disconnect(format!(
+                                        "Connection failed: {} giving up after {} attempts",
+                                        err, attempt
+                                    )),
+                                    &MessageSender { tx },
+                                )
+                                .unwrap();
+                                break;
+                            };
+                            attempt += 1;
+
+                            tokio::select! {
+                                _ = tokio::time::sleep(delay) => {}
+                                _ = wait_for_shutdown(&mut shutdown_rx) => break,
+                            }
                         }
                     }
                 }
             }
         });
 
-        tokio::time::sleep(Duration::from_secs(1)).await;
+        match tokio::time::timeout(self.connect_timeout, ready_rx).await {
+            Ok(Ok(())) => {}
+            // Dropped without a signal when the task stops before it ever connected
+            Ok(Err(_)) => return Err(WsError::ConnectionError),
+            Err(_) => {
+                let _ = shutdown.send(true);
+                task.abort();
+                return Err(WsError::Timeout);
+            }
+        }
 
         Ok(WsClient {
+            router,
             sender: Arc::clone(&sender_holder),
+            pending,
+            shutdown,
+            task,
+            request_timeout: self.request_timeout,
         })
     }
 }
 
+// Resolves once `WsClient::close` is called, never if the client is dropped without closing
+async fn wait_for_shutdown(shutdown: &mut watch::Receiver<bool>) {
+    if shutdown.wait_for(|closed| *closed).await.is_err() {
+        std::future::pending::<()>().await;
+    }
+}
+
+// Resolves when the next heartbeat ping is due, never if the heartbeat is disabled
+async fn next_ping(pings: &mut Option<tokio::time::Interval>) {
+    match pings {
+        Some(pings) => {
+            pings.tick().await;
+        }
+        None => std::future::pending::<()>().await,
+    }
+}
+
+// Resolves once nothing has been received for `limit`, restarted for every received frame
+async fn wait_for_silence(limit: Option<Duration>) {
+    match limit {
+        Some(limit) => tokio::time::sleep(limit).await,
+        None => std::future::pending::<()>().await,
+    }
+}
+
 // The actual WebSocket client (runtime instance)
 pub struct WsClient {
+    router: Arc<Router>,
     sender: Arc<Mutex<Option<MessageSender>>>,
+    pending: PendingRequests,
+    shutdown: watch::Sender<bool>,
+    task: JoinHandle<()>,
+    request_timeout: Duration,
 }
 
 impl WsClient {
@@ -495,7 +925,7 @@ impl WsClient {
         message: &WsMessage,
         sender: &MessageSender,
     ) -> Result<(), WsError> {
-        router.route_message(&message, sender)
+        router.route_message(message, sender)
     }
     pub(crate) fn send_connect_message(
         router: &Router,
@@ -506,21 +936,31 @@ impl WsClient {
     }
     pub(crate) fn handle_text_message(
         router: &Router,
+        pending: &PendingRequests,
         text: &str,
         sender: &MessageSender,
     ) -> Result<(), WsError> {
         let message: WsMessage = serde_json::from_str(text)
             .map_err(|_| WsError::InvalidMessageReceived(text.to_string()))?;
+
+        // Hand responses to whoever is awaiting them, callbacks are still invoked as usual
+        if let Some(ref_id) = &message.ref_id
+            && let Some(waiter) = pending.lock().unwrap().remove(ref_id)
+        {
+            let _ = waiter.send(Ok(message.clone()));
+        }
+
         router.route_message(&message, sender)
     }
 
-    // Public methods for sending messages (only available after build)
+    // Public methods for sending messages (only available after build),
+    // these fail with `WsError::NotConnected` while (re)connecting
     pub fn send_message(&self, message: WsMessage) -> Result<(), WsError> {
         let sender_guard = self.sender.lock().unwrap();
         if let Some(sender) = sender_guard.as_ref() {
             sender.send_message(message)
         } else {
-            Err(WsError::ConnectionError)
+            Err(WsError::NotConnected)
         }
     }
 
@@ -538,14 +978,18 @@ impl WsClient {
         }
     }
 
-    pub fn send_request(&self, route: &str, payload: serde_json::Value) -> Result<String, WsError> {
+    // Requests may not impersonate the client itself, e.g. by signing in again
+    fn check_route(route: &str) -> Result<(), WsError> {
         let route_parsed =
             Route::parse(route).map_err(|_| WsError::InvalidPath(route.to_string()))?;
-        if route_parsed.protocol == "internal" {
-            return Err(WsError::ReservedPath(
-                "Can't send on internal routes".to_string(),
-            ));
+        if route_parsed.protocol == "@internal" || Router::is_path_reserved(route_parsed.base_path()) {
+            return Err(WsError::ReservedPath(route.to_string()));
         }
+        Ok(())
+    }
+
+    pub fn send_request(&self, route: &str, payload: serde_json::Value) -> Result<String, WsError> {
+        Self::check_route(route)?;
         let sender_guard = self.sender.lock().unwrap();
         if let Some(sender) = sender_guard.as_ref() {
             sender.send_request(route, payload)
@@ -554,7 +998,177 @@ impl WsClient {
         }
     }
 
+    /**
+    Set the online status of the logged in user
+
+    # Arguments
+    - `status`: The status to show to other users
+
+    # Returns
+    The id of the request, referenced by the `cmd/status/set:ok` acknowledgement
+    */
+    pub fn set_status(&self, status: StatusType) -> Result<String, WsError> {
+        self.send_request("@wfm|cmd/status/set", json!({ "status": status }))
+    }
+
+    /**
+    Get notified about new orders placed on an item, delivered to the
+    [`on_new_order`][WsClientBuilder::on_new_order] callback
+
+    # Arguments
+    - `slug`: The slug of the item (e.g. `yareli_prime_set`)
+
+    # Returns
+    The id of the request, referenced by the `cmd/subscribe/newOrders:ok` acknowledgement
+    */
+    pub fn subscribe_item(&self, slug: &str) -> Result<String, WsError> {
+        self.send_request(SUBSCRIBE_ITEM_ROUTE, json!({ "itemSlug": slug }))
+    }
+
+    /**
+    Send a chat message to another user, e.g. to reach out after finding a matching order
+
+    # Arguments
+    - `user_id`: The id of the recipient (see [`MinimalUser`][crate::types::user::MinimalUser])
+    - `text`: The message to send
+
+    # Returns
+    - The id of the request, referenced by the `cmd/chats/sendMessage:ok` acknowledgement
+    - `WsError::InvalidMessage` if the text is empty
+    */
+    pub fn send_private_message(&self, user_id: &str, text: &str) -> Result<String, WsError> {
+        if text.trim().is_empty() {
+            return Err(WsError::InvalidMessage);
+        }
+        self.send_request(
+            PRIVATE_MESSAGE_ROUTE,
+            json!({ "userId": user_id, "text": text }),
+        )
+    }
+
+    /**
+    Send a request and wait for the message referencing it (through `refId`)
+
+    # Arguments
+    - `route`: The full route to send to (e.g. `@wfm|cmd/status/set`)
+    - `payload`: The payload of the request
+
+    # Returns
+    - The response message
+    - `WsError::ReservedPath` if the route is used by the client internally
+    - `WsError::NotConnected` if the client is not connected at the moment
+    - `WsError::Cancelled` if the request was cancelled using `cancel_request`
+    - `WsError::ConnectionError` if the connection was lost before a response arrived
+    - `WsError::Timeout` if no response arrived in time, see [`WsClientBuilder::with_request_timeout`]
+    */
+    pub async fn request(
+        &self,
+        route: &str,
+        payload: serde_json::Value,
+    ) -> Result<WsMessage, WsError> {
+        self.request_message(WsMessage::new(route, Some(payload)))
+            .await
+    }
+
+    /**
+    Same as `request`, but sends a prebuilt message, useful to know the request id
+    up front in order to cancel it from elsewhere
+
+    # Arguments
+    - `message`: The request message, see [`WsMessage::new`]
+    */
+    pub async fn request_message(&self, message: WsMessage) -> Result<WsMessage, WsError> {
+        self.request_message_with_timeout(message, self.request_timeout)
+            .await
+    }
+
+    /**
+    Same as `request`, but waits for the response for the given time instead of the configured
+    request timeout, e.g. for requests known to take long to be acknowledged
+
+    # Arguments
+    - `route`: The full route to send to (e.g. `@wfm|cmd/status/set`)
+    - `payload`: The payload of the request
+    - `timeout`: How long to wait for the response before failing with `WsError::Timeout`
+    */
+    pub async fn request_with_timeout(
+        &self,
+        route: &str,
+        payload: serde_json::Value,
+        timeout: Duration,
+    ) -> Result<WsMessage, WsError> {
+        self.request_message_with_timeout(WsMessage::new(route, Some(payload)), timeout)
+            .await
+    }
+
+    async fn request_message_with_timeout(
+        &self,
+        message: WsMessage,
+        timeout: Duration,
+    ) -> Result<WsMessage, WsError> {
+        Self::check_route(&message.route)?;
+        let id = message.id.clone().ok_or(WsError::InvalidMessage)?;
+
+        let (tx, rx) = oneshot::channel();
+        self.pending.lock().unwrap().insert(id.clone(), tx);
+
+        if let Err(e) = self.send_message(message) {
+            self.pending.lock().unwrap().remove(&id);
+            return Err(e);
+        }
+
+        match tokio::time::timeout(timeout, rx).await {
+            Ok(response) => response.map_err(|_| WsError::Cancelled)?,
+            Err(_) => {
+                self.pending.lock().unwrap().remove(&id);
+                Err(WsError::Timeout)
+            }
+        }
+    }
+
+    /**
+    Stop waiting for the response of a request sent through `request_message`
+
+    # Arguments
+    - `id`: The id of the request message
+
+    # Returns
+    Whether there was a pending request with the given id
+    */
+    pub fn cancel_request(&self, id: &str) -> bool {
+        self.pending.lock().unwrap().remove(id).is_some()
+    }
+
+    /// Whether messages can be sent right now, `false` while (re)connecting or once closed
+    pub fn is_connected(&self) -> bool {
+        self.sender.lock().unwrap().is_some()
+    }
+
+    /// The latest state reported to `WsClientBuilder::on_connection_change`
+    pub fn connection_state(&self) -> ConnectionState {
+        self.router.state.lock().unwrap().clone()
+    }
+
     pub fn get_sender(&self) -> Option<MessageSender> {
         self.sender.lock().unwrap().clone()
     }
+
+    /**
+    Close the connection and stop reconnecting
+
+    # Note
+    The `internal/disconnected` callback is invoked one last time, should the background
+    task not stop within 5 seconds it is aborted
+    */
+    pub async fn close(self) {
+        let _ = self.shutdown.send(true);
+
+        let mut task = self.task;
+        if tokio::time::timeout(Duration::from_secs(5), &mut task)
+            .await
+            .is_err()
+        {
+            task.abort();
+        }
+    }
 }
@@ -1,21 +1,49 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
 
 use serde::Deserialize;
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug)]
 pub enum AuthError {
+    /// The login succeeded but the response did not contain the user
     NoUser,
+    /// The login succeeded but the response did not contain a token
+    NoToken,
+    /// The response body could not be decoded
     ParsingError,
+    /// The account has two-factor authentication enabled, log in using `login_with_2fa`
+    TwoFactorRequired,
+    /// Unknown email or wrong password
+    InvalidCredentials,
+    AccountBanned,
+    /// Too many sign in attempts, try again later
+    RateLimited,
+    /// The token given to resume a session was rejected, e.g. it expired or was signed out
+    TokenExpired,
+    /// The request failed for another reason than the credentials, e.g. a network error
+    Api(ApiError),
     Unknown(String),
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug)]
 pub enum ApiError {
     ParsingError(String),
-    RequestError,
+    /// The request could not be sent or the response not be read, e.g. timeouts or DNS failures
+    Request(reqwest::Error),
+    /// The response did not match the expected shape
+    Parse(serde_json::Error),
     Unauthorized,
+    /// The token of an authenticated client was rejected, log in again to get a new one
+    TokenExpired,
     NotFound(String),
     Forbidden,
     WFMError(ErrorResponse),
+    /// Arguments rejected before sending anything, e.g. more stars than a sculpture has sockets
+    InvalidInput(String),
+    /// Any other unsuccessful status, e.g. 429 when rate limited or 5xx on server errors
+    Http {
+        status: u16,
+        body: String,
+    },
     Unknown(String),
 }
 
@@ -39,8 +67,197 @@ pub enum WsError {
     InvalidPath(String),
     AlreadyRegistered(String),
     InvalidMessageReceived(String),
+    InvalidPayload(String),
     ConnectionError,
     InvalidMessage,
     SendError(String),
     NotConnected,
+    Cancelled,
+    Timeout,
+}
+
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthError::NoUser => write!(f, "no user returned by the login"),
+            AuthError::NoToken => write!(f, "no token returned by the login"),
+            AuthError::ParsingError => write!(f, "unable to parse the login response"),
+            AuthError::TwoFactorRequired => write!(f, "a two-factor authentication code is required"),
+            AuthError::InvalidCredentials => write!(f, "invalid email or password"),
+            AuthError::AccountBanned => write!(f, "the account is banned"),
+            AuthError::RateLimited => write!(f, "too many sign in attempts"),
+            AuthError::TokenExpired => write!(f, "the session token has expired"),
+            AuthError::Api(e) => write!(f, "authentication failed: {}", e),
+            AuthError::Unknown(msg) => write!(f, "authentication failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AuthError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AuthError::Api(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ApiError> for AuthError {
+    fn from(e: ApiError) -> Self {
+        match e {
+            ApiError::TokenExpired => AuthError::TokenExpired,
+            e => AuthError::Api(e),
+        }
+    }
+}
+
+/**
+INTERNAL: Error body of the v1 API, message codes keyed by the offending input (or `_form`)
+*/
+#[derive(Deserialize)]
+struct V1ErrorResponse {
+    #[serde(default)]
+    error: BTreeMap<String, serde_json::Value>,
 }
+
+impl V1ErrorResponse {
+    // The message codes of a field, whether given as a single string or a list of them
+    fn codes(&self, field: &str) -> Vec<&str> {
+        match self.error.get(field) {
+            Some(serde_json::Value::Array(values)) => values.iter().filter_map(|v| v.as_str()).collect(),
+            Some(value) => value.as_str().into_iter().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    // Every message code, ordered by field
+    fn all_codes(&self) -> Vec<&str> {
+        self.error.keys().flat_map(|field| self.codes(field)).collect()
+    }
+}
+
+// Form level errors (`_form`) of a rejected sign in
+const TWO_FACTOR_REQUIRED: &str = "app.account.2fa_required";
+const ACCOUNT_BANNED: &str = "app.account.banned";
+
+impl AuthError {
+    /**
+    INTERNAL: Map a rejected sign in to the matching variant
+
+    # Note
+    Errors on the `email` or `password` inputs mean the credentials were wrong, an error on
+    the `code` input or a `2fa_required` form error that a verification code is needed
+
+    # Arguments
+    - `status`: The HTTP status of the response
+    - `body`: The response body
+    */
+    pub(crate) fn from_response(status: u16, body: &str) -> Self {
+        if status == 429 {
+            return AuthError::RateLimited;
+        }
+
+        let Ok(response) = serde_json::from_str::<V1ErrorResponse>(body) else {
+            return AuthError::Unknown(format!("HTTP {}: {}", status, body));
+        };
+        let form = response.codes("_form");
+
+        if form.contains(&TWO_FACTOR_REQUIRED) || response.error.contains_key("code") {
+            AuthError::TwoFactorRequired
+        } else if form.contains(&ACCOUNT_BANNED) {
+            AuthError::AccountBanned
+        } else if response.error.contains_key("email") || response.error.contains_key("password") {
+            AuthError::InvalidCredentials
+        } else {
+            AuthError::Unknown(format!("HTTP {}: {}", status, response.all_codes().join(", ")))
+        }
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiError::ParsingError(msg) => write!(f, "unable to parse the response: {}", msg),
+            ApiError::Request(e) => write!(f, "unable to send the request: {}", e),
+            ApiError::Parse(e) => write!(f, "unable to parse the response: {}", e),
+            ApiError::Unauthorized => write!(f, "unauthorized"),
+            ApiError::TokenExpired => write!(f, "the session token has expired"),
+            ApiError::NotFound(msg) => write!(f, "not found: {}", msg),
+            ApiError::Forbidden => write!(f, "forbidden"),
+            ApiError::WFMError(response) => write!(f, "rejected by warframe.market: {}", response),
+            ApiError::InvalidInput(msg) => write!(f, "invalid input: {}", msg),
+            ApiError::Http { status, body } => {
+                write!(f, "unexpected HTTP status {}: {}", status, body)
+            }
+            ApiError::Unknown(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ApiError::Request(e) => Some(e),
+            ApiError::Parse(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for ApiError {
+    fn from(e: reqwest::Error) -> Self {
+        ApiError::Request(e)
+    }
+}
+
+impl From<serde_json::Error> for ApiError {
+    fn from(e: serde_json::Error) -> Self {
+        ApiError::Parse(e)
+    }
+}
+
+impl fmt::Display for ErrorResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut messages: Vec<String> = self.error.request.clone().unwrap_or_default();
+
+        if let Some(inputs) = &self.error.inputs {
+            let mut inputs: Vec<_> = inputs.iter().collect();
+            inputs.sort();
+            messages.extend(
+                inputs
+                    .into_iter()
+                    .map(|(input, error)| format!("{}: {}", input, error)),
+            );
+        }
+
+        if messages.is_empty() {
+            write!(f, "no details given")
+        } else {
+            write!(f, "{}", messages.join(", "))
+        }
+    }
+}
+
+impl fmt::Display for WsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WsError::ReservedPath(path) => write!(f, "path is reserved by the client: {}", path),
+            WsError::InvalidPath(path) => write!(f, "invalid route: {}", path),
+            WsError::AlreadyRegistered(path) => {
+                write!(f, "a callback is already registered for: {}", path)
+            }
+            WsError::InvalidMessageReceived(text) => {
+                write!(f, "received an invalid message: {}", text)
+            }
+            WsError::InvalidPayload(msg) => write!(f, "unexpected payload: {}", msg),
+            WsError::ConnectionError => write!(f, "connection error"),
+            WsError::InvalidMessage => write!(f, "message is missing an id"),
+            WsError::SendError(msg) => write!(f, "unable to send the message: {}", msg),
+            WsError::NotConnected => write!(f, "not connected"),
+            WsError::Cancelled => write!(f, "request was cancelled"),
+            WsError::Timeout => write!(f, "timed out waiting for a response"),
+        }
+    }
+}
+
+impl std::error::Error for WsError {}
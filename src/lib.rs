@@ -5,10 +5,10 @@ The `wf-market` crate provides an abstraction on top of the **Warframe Market AP
 It handles a lot of actions you'd like to perform as trader.
 - A simple to use async [`Client`]
 - Abstracted object to easily manage both [`Items`][client::item::Item] and [`Orders`][client::order::Order]
-- *(COMING SOON)* WebSocket support to keep data up to date
+- WebSocket support to keep data up to date, see [`ws`][client::ws]
 
 ## Constructing and Authenticating a client
-```rust
+```rust,no_run
 use wf_market::{
     Client,
     utils::generate_device_id,
@@ -25,33 +25,26 @@ async fn main() {
     let authenticated_client: Client<Authenticated> = {
         Client::new()
             .login("username", "password", generate_device_id().as_str())
+            .await
+            .unwrap()
     };
 }
 ```
 NOTE: Not reusing the device_id may generate multiple devices on a user's device
 
 ## Find the price of Ayatan Sculptures
-```rust
-use wf_market::{
-    client::Client,
-    utils::generate_device_id,
-};
+```rust,no_run
+use wf_market::client::Client;
 
 #[tokio::main]
 async fn main() {
-    let mut client = Client::new();
+    let client = Client::new();
 
-    match client.get_items().await {
-        Ok(mut items) => {
-            items = items.iter()
-                        .filter(|item| item.is_sculpture())
-                        .collect();
+    match client.rank_sculptures_by_value().await {
+        Ok(sculptures) => {
             println!("Sculpture Valuation:");
-            for item in items {
-                let sculpture = item.to_sculpture().unwrap();
-                println!("{}: {} endo", 
-                    sculpture.get_name(), 
-                    sculpture.calculate_value(None, None));
+            for (sculpture, endo) in sculptures {
+                println!("{}: {} endo", sculpture.get_name(), endo);
             }
         },
         Err(e) => println!("Error: {:?}", e),
@@ -60,6 +53,8 @@ async fn main() {
 ```
 */
 
+#![allow(clippy::result_large_err)]
+
 pub mod types;
 pub mod error;
 pub mod client;
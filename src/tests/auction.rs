@@ -0,0 +1,60 @@
+use crate::types::auction::{Auction, RivenAttributeValue};
+use crate::types::request::RivenAuctionRequest;
+use crate::types::riven::Polarity;
+use serde_json::json;
+
+#[test]
+fn riven_auction_request_body() {
+    let req = RivenAuctionRequest::new(
+        "soma",
+        "visi-ritacron",
+        vec![RivenAttributeValue::new("critical_chance", true, 120.5)],
+        Polarity::Madurai,
+        100,
+    )
+    .with_buyout_price(250)
+    .with_re_rolls(3);
+
+    let body = serde_json::to_value(&req).unwrap();
+    assert_eq!(body["starting_price"], 100);
+    assert_eq!(body["buyout_price"], 250);
+    assert_eq!(body["item"]["type"], "riven");
+    assert_eq!(body["item"]["weapon_url_name"], "soma");
+    assert_eq!(body["item"]["polarity"], "madurai");
+    assert_eq!(body["item"]["re_rolls"], 3);
+    assert_eq!(body["item"]["attributes"][0]["url_name"], "critical_chance");
+}
+
+#[test]
+fn auction_response() {
+    let auction: Auction = serde_json::from_value(json!({
+        "id": "auction",
+        "starting_price": 100,
+        "buyout_price": null,
+        "top_bid": null,
+        "minimal_reputation": 0,
+        "note": "",
+        "private": false,
+        "visible": true,
+        "closed": false,
+        "is_direct_sell": false,
+        "platform": "pc",
+        "created": "2025-06-01T12:00:00.000+00:00",
+        "updated": "2025-06-01T12:00:00.000+00:00",
+        "item": {
+            "type": "riven",
+            "weapon_url_name": "soma",
+            "name": "visi-ritacron",
+            "attributes": [{ "url_name": "critical_chance", "positive": true, "value": 120.5 }],
+            "mastery_level": 8,
+            "re_rolls": 3,
+            "polarity": "madurai",
+            "mod_rank": 0,
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(auction.buyout_price, None);
+    assert_eq!(auction.item.polarity, Polarity::Madurai);
+    assert_eq!(auction.item.attributes.len(), 1);
+}
@@ -1,7 +1,7 @@
 use crate::client::{Authenticated, Client};
 use crate::error::AuthError;
 use crate::types::item::OrderType;
-use crate::types::request::OrderCreationRequest;
+use crate::types::request::{OrderCreationRequest, OrderUpdateParams};
 use dotenv::dotenv;
 use std::env;
 
@@ -26,7 +26,7 @@ async fn test_authentication() {
 
 #[tokio::test]
 async fn test_my_orders() {
-    let mut client = setup_client().await.unwrap();
+    let client = setup_client().await.unwrap();
 
     client.my_orders().await.unwrap();
 }
@@ -68,3 +68,131 @@ async fn delete_order() {
     let rep = client.delete_order(id).await.unwrap();
     println!("Delete order response: {:?}", rep.object);
 }
+
+#[tokio::test]
+async fn refresh_orders() {
+    let mut client = setup_client().await.unwrap();
+
+    let count = client.orders.len();
+    let orders = client.refresh_orders().await.unwrap();
+    assert_eq!(orders.len(), count);
+}
+
+#[tokio::test]
+async fn update_orders() {
+    let client = setup_client().await.unwrap();
+
+    let updates = client
+        .my_orders()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|order| {
+            let visible = order.get_visible();
+            (order, OrderUpdateParams::new().with_visible(visible))
+        })
+        .collect::<Vec<_>>();
+    let count = updates.len();
+
+    let results = client.update_orders(updates).await;
+    assert_eq!(results.len(), count);
+    assert!(results.iter().all(|r| r.is_ok()));
+}
+
+#[tokio::test]
+async fn transactions() {
+    let client = setup_client().await.unwrap();
+
+    let transactions = client.get_transactions().await.unwrap();
+    assert!(transactions.windows(2).all(|t| t[0].created_at >= t[1].created_at));
+}
+
+#[tokio::test]
+async fn delete_order_object() {
+    let id = "5bc1ab93b919f200c18c10ef"; // Adaptation Max Rank 10
+    let client = setup_client().await.unwrap();
+
+    let order = client
+        .create_order(OrderCreationRequest::new(id, OrderType::Sell, 98, 1, false).with_mods(10))
+        .await
+        .unwrap();
+    let order_id = order.get_id();
+
+    let deleted = order.delete(&client).await.unwrap();
+    assert_eq!(deleted.get_id(), order_id);
+}
+
+#[tokio::test]
+async fn empty_update_is_skipped() {
+    let client = setup_client().await.unwrap();
+    let order = client.my_orders().await.unwrap().remove(0);
+
+    let changes = order.diff(&order);
+    assert!(changes.is_empty());
+
+    let updated = client.update_order(order.clone(), changes).await.unwrap();
+    assert_eq!(updated, order);
+}
+
+#[tokio::test]
+async fn logout() {
+    let client = setup_client().await.unwrap();
+
+    let client = client.logout().await.unwrap();
+    assert!(client.user.is_none());
+    assert!(client.orders.is_empty());
+}
+
+#[tokio::test]
+async fn resume_session() {
+    let client = setup_client().await.unwrap();
+
+    let resumed = Client::new()
+        .login_with_token(&client.get_token(), &client.get_device_id())
+        .await
+        .unwrap();
+    assert_eq!(resumed.current_user().id, client.current_user().id);
+    assert_eq!(resumed.reputation(), client.reputation());
+
+    let invalid = Client::new().login_with_token("not a token", "dev").await;
+    assert!(matches!(invalid, Err(AuthError::TokenExpired)));
+}
+
+#[tokio::test]
+async fn expired_token() {
+    let client = setup_client().await.unwrap();
+    let (token, device_id) = (client.get_token(), client.get_device_id());
+    client.logout().await.unwrap();
+
+    match Client::new().login_with_token(&token, &device_id).await {
+        Err(AuthError::TokenExpired) => {}
+        _ => panic!("a signed out token should be rejected"),
+    }
+}
+
+#[tokio::test]
+async fn auto_reauth() {
+    let client = setup_client().await.unwrap();
+    let user = env::var("TEST_USER").unwrap();
+    let pass = env::var("TEST_PASS").unwrap();
+
+    let resumed = Client::new()
+        .login_with_token(&client.get_token(), &client.get_device_id())
+        .await
+        .unwrap()
+        .with_auto_reauth(&user, &pass);
+    let expired_token = resumed.get_token();
+    client.logout().await.unwrap();
+
+    resumed.my_orders().await.unwrap();
+    assert_ne!(resumed.get_token(), expired_token);
+}
+
+#[tokio::test]
+async fn wrong_password() {
+    dotenv().ok();
+    let user = env::var("TEST_USER").expect("TEST_USER must be set in .env for integration tests");
+
+    let result = Client::new().login(&user, "definitely not the password", "dev").await;
+    assert!(matches!(result, Err(AuthError::InvalidCredentials)));
+}
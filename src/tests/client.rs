@@ -1,5 +1,12 @@
+use super::helpers::{ayr, order};
+use super::mock::MockTransport;
 use crate::client::Client;
-use crate::types::filter::OrdersTopFilters;
+use crate::error::ApiError;
+use crate::types::filter::{ItemSearch, OrdersFilters, OrdersTopFilters};
+use crate::types::item::OrderType;
+use crate::types::user::StatusType;
+use serde_json::json;
+use std::time::Duration;
 
 const TEST_ITEM: &str = "yareli_prime_set";
 const TEST_SCULPTURE: &str = "ayatan_ayr_sculpture";
@@ -8,27 +15,36 @@ const TEST_MOD: &str = "primed_flow";
 
 #[tokio::test]
 async fn test_orders() {
-    let mut client = Client::new();
+    let client = Client::new();
 
     let _ = client.get_orders(TEST_ITEM).await.unwrap();
 }
 
 #[tokio::test]
 async fn test_orders_top() {
-    let mut client = Client::new();
+    let transport = MockTransport::default().with_route(
+        &format!("/orders/item/{}/top", TEST_ITEM),
+        json!({
+            "buy": [order("buy", 40).json(), order("buy", 35).json()],
+            "sell": [order("sell", 45).json(), order("sell", 50).json()],
+        }),
+    );
+    let client = Client::new().with_transport(transport);
 
-    let items = client.get_orders_top(TEST_ITEM, None).await.unwrap();
-    println!("Orders for {}: {}", TEST_ITEM, items.len());
+    let orders = client.get_orders_top(TEST_ITEM, None).await.unwrap();
+    assert_eq!(orders.len(), 4);
+    assert_eq!(orders[0].get_sell_type(), OrderType::Buy);
+
+    let top = client.get_orders_top_split(TEST_ITEM, None).await.unwrap();
+    assert_eq!(top.lowest_sell_price(true), Some(45));
+    assert_eq!(top.highest_buy_price(true), Some(40));
 }
 
 #[tokio::test]
 async fn test_filtered_orders_top() {
-    let mut client = Client::new();
+    let client = Client::new();
 
-    let filters = OrdersTopFilters {
-        rank: Some(10),
-        ..Default::default()
-    };
+    let filters = OrdersTopFilters::new().with_rank(10);
 
     let mods = client
         .get_orders_top(TEST_MOD, Some(filters))
@@ -42,21 +58,21 @@ async fn test_filtered_orders_top() {
 
 #[tokio::test]
 async fn all_items() {
-    let mut client = Client::new();
+    let client = Client::new();
 
     let _ = client.get_items().await.unwrap();
 }
 
 #[tokio::test]
 async fn get_item() {
-    let mut client = Client::new();
+    let client = Client::new();
 
     let _ = client.get_item(TEST_ITEM).await.unwrap();
 }
 
 #[tokio::test]
 async fn convert_mod() {
-    let mut client = Client::new();
+    let client = Client::new();
     let items = client.get_items().await.unwrap();
 
     if let Some(item) = items.iter().find(|i| i.get_slug() == TEST_MOD) {
@@ -73,26 +89,219 @@ async fn convert_mod() {
 
 #[tokio::test]
 async fn convert_sculpture() {
-    let mut client = Client::new();
-    let items = client.get_items().await.unwrap();
+    let transport = MockTransport::default().with_route("/items", json!([ayr()]));
+    let client = Client::new().with_transport(transport.clone());
 
-    if let Some(item) = items.iter().find(|i| i.get_slug() == TEST_SCULPTURE) {
-        match item.is_sculpture() {
-            true => {
-                let sculpture = item.to_sculpture().unwrap();
-                let value = sculpture.calculate_value(None, None);
+    let items = client.get_items().await.unwrap();
+    let sculpture = items[0].to_sculpture().unwrap();
+    assert_eq!(sculpture.calculate_value(None, None).unwrap(), SCULPTURE_VALUE);
 
-                assert_eq!(value, SCULPTURE_VALUE);
-            }
-            false => {
-                panic!("{} is not a sculpture", item.get_slug());
-            }
-        }
-    }
+    // Served from the items cache the second time
+    client.get_items().await.unwrap();
+    assert_eq!(transport.requests.lock().unwrap().len(), 1);
 }
 
 #[tokio::test]
 async fn all_rivens() {
-    let mut client = Client::new();
+    let client = Client::new();
     let _ = client.get_rivens().await.unwrap();
 }
+
+#[tokio::test]
+async fn invalidate_items_cache() {
+    let client = Client::new();
+
+    let cached = client.get_items().await.unwrap();
+    client.invalidate_items_cache();
+    let fetched = client.get_items().await.unwrap();
+
+    assert_eq!(cached.len(), fetched.len());
+}
+
+#[tokio::test]
+async fn search_items() {
+    let client = Client::new();
+
+    let query = ItemSearch {
+        name_contains: Some("AYATAN".to_string()),
+        ..Default::default()
+    };
+
+    let items = client.search_items(&query).await.unwrap();
+    assert!(items.iter().any(|item| item.get_slug() == TEST_SCULPTURE));
+}
+
+#[tokio::test]
+async fn items_paged() {
+    let client = Client::new();
+    let total = client.get_items().await.unwrap().len();
+
+    let mut seen = 0;
+    let mut offset = Some(0);
+    while let Some(current) = offset {
+        let page = client.get_all_items_paged(current, 500).await.unwrap();
+        assert_eq!(page.total, total);
+
+        seen += page.items.len();
+        offset = page.next;
+    }
+
+    assert_eq!(seen, total);
+}
+
+#[tokio::test]
+async fn item_statistics() {
+    let client = Client::new();
+
+    let stats = client.get_item_statistics(TEST_ITEM).await.unwrap();
+    assert!(!stats.closed.last_90_days.is_empty());
+}
+
+#[tokio::test]
+async fn refresh_rivens() {
+    let client = Client::new();
+
+    let cached = client.get_rivens().await.unwrap();
+    let fetched = client.get_rivens_refresh(true).await.unwrap();
+
+    assert_eq!(cached.len(), fetched.len());
+}
+
+#[tokio::test]
+async fn test_orders_top_split() {
+    let client = Client::new();
+
+    let top = client.get_orders_top_split(TEST_ITEM, None).await.unwrap();
+    assert!(top.buy.iter().all(|o| o.get_sell_type() == OrderType::Buy));
+    assert!(top.sell.iter().all(|o| o.get_sell_type() == OrderType::Sell));
+    assert!(top.sell.windows(2).all(|w| w[0].get_platinum() <= w[1].get_platinum()));
+}
+
+#[tokio::test]
+async fn item_by_game_ref() {
+    let client = Client::new();
+    let item = client.get_item(TEST_ITEM).await.unwrap();
+    let game_ref = item.game_ref().unwrap();
+
+    let found = client.item_by_game_ref(&game_ref).await.unwrap().unwrap();
+    assert_eq!(found.get_slug(), TEST_ITEM);
+}
+
+#[tokio::test]
+async fn enrich_orders() {
+    let client = std::sync::Arc::new(Client::new());
+    let orders = client.get_orders(TEST_ITEM).await.unwrap();
+    let count = orders.len();
+
+    let enriched = tokio::spawn({
+        let client = client.clone();
+        async move { client.enrich_orders(orders).await.unwrap() }
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(enriched.len(), count);
+    assert!(enriched.iter().all(|o| o.item.is_some()));
+}
+
+#[tokio::test]
+async fn public_user() {
+    let client = Client::new();
+    let orders = client.get_orders(TEST_ITEM).await.unwrap();
+    let seller = orders[0].get_user().unwrap().name.to_lowercase();
+
+    let user = client.get_user(&seller).await.unwrap();
+    assert_eq!(user.name.to_lowercase(), seller);
+
+    let _ = client.get_user_orders(&seller).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_filtered_orders() {
+    let client = Client::new();
+
+    let filters = OrdersFilters {
+        order_type: Some(OrderType::Sell),
+        online_only: true,
+        ..Default::default()
+    };
+
+    let orders = client.get_orders_filtered(TEST_ITEM, filters).await.unwrap();
+    assert!(orders.iter().all(|o| o.get_sell_type() == OrderType::Sell));
+    assert!(
+        orders
+            .iter()
+            .all(|o| o.get_user().unwrap().status_type != StatusType::Offline)
+    );
+}
+
+#[tokio::test]
+async fn request_timeout() {
+    let client = Client::new()
+        .with_retries(0)
+        .with_timeout(Duration::from_millis(1));
+
+    match client.get_item(TEST_ITEM).await {
+        Err(ApiError::Request(e)) => assert!(e.is_timeout()),
+        _ => panic!("request should have timed out"),
+    }
+}
+
+#[tokio::test]
+async fn unreachable_proxy() {
+    let proxy = reqwest::Proxy::all("http://127.0.0.1:9").unwrap();
+    let client = Client::new().with_retries(0).with_proxy(proxy);
+
+    assert!(matches!(client.get_item(TEST_ITEM).await, Err(ApiError::Request(_))));
+}
+
+#[tokio::test]
+async fn custom_http_client() {
+    let client = Client::new().with_http_client(reqwest::Client::new());
+
+    let item = client.get_item(TEST_ITEM).await.unwrap();
+    assert_eq!(item.get_slug(), TEST_ITEM);
+}
+
+#[tokio::test]
+async fn orders_for_items() {
+    let client = Client::new();
+
+    let results = client
+        .get_orders_for_items(&[TEST_ITEM, TEST_MOD, "not_an_item"])
+        .await;
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].0, TEST_ITEM);
+    assert!(results[0].1.is_ok());
+    assert!(results[1].1.is_ok());
+    assert!(results[2].1.is_err());
+}
+
+#[tokio::test]
+async fn orders_with_stats() {
+    let client = Client::new();
+
+    let market = client.get_item_orders_with_stats(TEST_ITEM, None).await.unwrap();
+    assert!(!market.top_orders.sell.is_empty());
+    assert!(!market.stats.closed.last_90_days.is_empty());
+}
+
+#[tokio::test]
+async fn ping() {
+    let client = Client::new();
+    client.ping().await.unwrap();
+
+    let proxy = reqwest::Proxy::all("http://127.0.0.1:9").unwrap();
+    let unreachable = Client::new().with_retries(0).with_proxy(proxy);
+    assert!(unreachable.ping().await.is_err());
+}
+
+#[tokio::test]
+async fn etag_cache() {
+    let client = Client::new().with_etag_cache(true);
+
+    let fetched = client.get_item(TEST_ITEM).await.unwrap();
+    let revalidated = client.get_item(TEST_ITEM).await.unwrap();
+    assert_eq!(fetched.get_id(), revalidated.get_id());
+}
@@ -0,0 +1,66 @@
+use crate::error::{ApiError, AuthError, ErrorResponse};
+use serde_json::json;
+
+#[test]
+fn wfm_error_summarizes_inputs() {
+    let response: ErrorResponse = serde_json::from_value(json!({
+        "apiVersion": "0.0.0",
+        "data": null,
+        "error": {
+            "request": ["app.form.invalid"],
+            "inputs": {
+                "quantity": "app.field.tooSmall",
+                "platinum": "app.field.tooSmall",
+            }
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(
+        ApiError::WFMError(response).to_string(),
+        "rejected by warframe.market: app.form.invalid, platinum: app.field.tooSmall, quantity: app.field.tooSmall"
+    );
+}
+
+#[test]
+fn parse_error_is_preserved() {
+    let err: ApiError = serde_json::from_str::<u32>("\"nope\"").unwrap_err().into();
+
+    assert!(matches!(&err, ApiError::Parse(e) if e.is_data()));
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[test]
+fn auth_error_from_response() {
+    let body = |messages: serde_json::Value| json!({ "error": messages }).to_string();
+
+    assert!(matches!(
+        AuthError::from_response(400, &body(json!({"password": ["app.account.password_invalid"]}))),
+        AuthError::InvalidCredentials
+    ));
+    assert!(matches!(
+        AuthError::from_response(400, &body(json!({"email": "app.account.email_not_exist"}))),
+        AuthError::InvalidCredentials
+    ));
+    assert!(matches!(
+        AuthError::from_response(403, &body(json!({"_form": ["app.account.banned"]}))),
+        AuthError::AccountBanned
+    ));
+    assert!(matches!(
+        AuthError::from_response(400, &body(json!({"_form": ["app.account.2fa_required"]}))),
+        AuthError::TwoFactorRequired
+    ));
+    assert!(matches!(
+        AuthError::from_response(400, &body(json!({"_form": ["app.account.password_reset_email_sent"]}))),
+        AuthError::Unknown(msg) if msg == "HTTP 400: app.account.password_reset_email_sent"
+    ));
+    assert!(matches!(
+        AuthError::from_response(400, &body(json!({"code": ["app.account.code_invalid"]}))),
+        AuthError::TwoFactorRequired
+    ));
+    assert!(matches!(AuthError::from_response(429, "Too Many Requests"), AuthError::RateLimited));
+    assert!(matches!(
+        AuthError::from_response(500, "<html>"),
+        AuthError::Unknown(msg) if msg == "HTTP 500: <html>"
+    ));
+}
@@ -0,0 +1,45 @@
+use crate::error::ApiError;
+use crate::types::filter::OrdersTopFilters;
+use crate::types::item::Item;
+use crate::types::user::StatusType;
+use serde_json::json;
+
+fn primed_flow() -> Item {
+    serde_json::from_value(json!({
+        "id": "primed_flow",
+        "slug": "primed_flow",
+        "maxRank": 10,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn top_filters_builder() {
+    let filters = OrdersTopFilters::new()
+        .with_max_rank(5)
+        .with_subtype("blueprint")
+        .with_user_activity(StatusType::InGame);
+
+    assert_eq!(filters.rank, None);
+    assert_eq!(filters.rank_lt, Some(6));
+    assert_eq!(filters.subtype.as_deref(), Some("blueprint"));
+    assert_eq!(filters.user_activity, Some(StatusType::InGame));
+}
+
+#[test]
+fn top_filters_validation() {
+    let item = primed_flow();
+
+    assert!(OrdersTopFilters::new().validate(None).is_ok());
+    assert!(OrdersTopFilters::new().with_rank(10).validate(Some(&item)).is_ok());
+
+    let contradicting = OrdersTopFilters::new().with_rank(10).with_max_rank(5);
+    assert!(matches!(contradicting.validate(None), Err(ApiError::InvalidInput(_))));
+
+    let stars = OrdersTopFilters::new().with_amber_stars(1);
+    assert!(stars.validate(None).is_ok());
+    assert!(matches!(stars.validate(Some(&item)), Err(ApiError::InvalidInput(_))));
+
+    let subtype = OrdersTopFilters::new().with_subtype("blueprint");
+    assert!(matches!(subtype.validate(Some(&item)), Err(ApiError::InvalidInput(_))));
+}
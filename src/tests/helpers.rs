@@ -0,0 +1,93 @@
+use crate::client::{Order, Unowned};
+use crate::types::item::OrderWithUser;
+use serde_json::{Value, json};
+
+/// Builds orders as returned by the item order listings, only the price and type are required
+pub(super) struct OrderBuilder {
+    order_type: &'static str,
+    platinum: u32,
+    visible: bool,
+    status: &'static str,
+    platform: &'static str,
+    stars: Option<(u8, u8)>,
+}
+
+pub(super) fn order(order_type: &'static str, platinum: u32) -> OrderBuilder {
+    OrderBuilder {
+        order_type,
+        platinum,
+        visible: true,
+        status: "ingame",
+        platform: "pc",
+        stars: None,
+    }
+}
+
+impl OrderBuilder {
+    pub(super) fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    pub(super) fn status(mut self, status: &'static str) -> Self {
+        self.status = status;
+        self
+    }
+
+    pub(super) fn platform(mut self, platform: &'static str) -> Self {
+        self.platform = platform;
+        self
+    }
+
+    pub(super) fn stars(mut self, cyan: u8, amber: u8) -> Self {
+        self.stars = Some((cyan, amber));
+        self
+    }
+
+    pub(super) fn json(&self) -> Value {
+        let mut order = json!({
+            "id": format!("{}-{}-{}", self.order_type, self.platinum, self.status),
+            "type": self.order_type,
+            "platinum": self.platinum,
+            "quantity": 1,
+            "visible": self.visible,
+            "itemId": "item",
+            "createdAt": "2025-06-01T12:00:00Z",
+            "updatedAt": "2025-06-01T12:00:00Z",
+            "user": {
+                "id": "user",
+                "ingameName": "Trader",
+                "reputation": 10,
+                "status": self.status,
+                "lastSeen": "2025-06-01T11:30:00Z",
+                "platform": self.platform,
+            },
+        });
+
+        if let Some((cyan, amber)) = self.stars {
+            order["cyanStars"] = json!(cyan);
+            order["amberStars"] = json!(amber);
+        }
+
+        order
+    }
+
+    pub(super) fn build(&self) -> Order<Unowned> {
+        let order: OrderWithUser = serde_json::from_value(self.json()).unwrap();
+
+        Order::with_user(&order)
+    }
+}
+
+/// The Ayr sculpture as listed by WFM, worth 1425 endo with all stars installed
+pub(super) fn ayr() -> Value {
+    json!({
+        "id": "ayr",
+        "slug": "ayatan_ayr_sculpture",
+        "tags": ["ayatan_sculpture"],
+        "maxAmberStars": 1,
+        "maxCyanStars": 3,
+        "baseEndo": 425,
+        "endoMultiplier": 1.1111111,
+    })
+}
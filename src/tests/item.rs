@@ -0,0 +1,370 @@
+use super::helpers::{ayr, order};
+use crate::client::{Client, Item, Sculpture};
+use crate::error::ApiError;
+use crate::types::item::{Item as ItemObject, OrderType, Relic, RelicRarity, RelicReward};
+use crate::types::request::OrderVariant;
+use serde_json::json;
+use std::collections::HashMap;
+
+fn anasa() -> Item<Sculpture> {
+    let item: ItemObject = serde_json::from_value(json!({
+        "id": "anasa",
+        "slug": "ayatan_anasa_sculpture",
+        "tags": ["ayatan_sculpture"],
+        "maxAmberStars": 2,
+        "maxCyanStars": 2,
+        "baseEndo": 450,
+        "endoMultiplier": 3.6,
+    }))
+    .unwrap();
+
+    Item::new(&item).to_sculpture().unwrap()
+}
+
+#[test]
+fn endo_per_platinum() {
+    let sculpture = anasa();
+
+    assert_eq!(sculpture.calculate_value(None, None).unwrap(), 3450);
+    assert_eq!(sculpture.endo_per_platinum(&order("sell", 10).build()).unwrap(), 345.0);
+    assert_eq!(sculpture.endo_per_platinum(&order("sell", 0).build()).unwrap(), f32::INFINITY);
+
+    let empty = sculpture.calculate_value(Some(0), Some(0)).unwrap() as f32;
+    assert_eq!(sculpture.endo_per_platinum(&order("sell", 10).stars(0, 0).build()).unwrap(), empty / 10.0);
+}
+
+#[test]
+fn socketless_sculpture_is_an_error() {
+    let item: ItemObject = serde_json::from_value(json!({
+        "id": "broken",
+        "slug": "ayatan_broken_sculpture",
+        "baseEndo": 450,
+        "endoMultiplier": 3.6,
+    }))
+    .unwrap();
+    let sculpture = Item::new(&item).to_sculpture().unwrap();
+
+    assert!(matches!(sculpture.calculate_value(None, None), Err(ApiError::ParsingError(_))));
+}
+
+#[test]
+fn partially_socketed_sculpture() {
+    let sculpture = anasa();
+
+    // (cyan, amber, endo)
+    let values = [
+        (1, 0, 950),
+        (0, 1, 1045),
+        (2, 0, 1540),
+        (1, 1, 1680),
+        (0, 2, 1820),
+        (2, 1, 2405),
+        (1, 2, 2590),
+        (2, 2, 3450),
+    ];
+
+    for (cyan, amber, endo) in values {
+        assert_eq!(sculpture.calculate_value(Some(cyan), Some(amber)).unwrap(), endo, "{} cyan, {} amber", cyan, amber);
+    }
+}
+
+#[test]
+fn too_many_stars_is_an_error() {
+    let sculpture = anasa();
+
+    assert_eq!(sculpture.calculate_value(Some(0), Some(0)).unwrap(), 450);
+    assert!(matches!(sculpture.calculate_value(Some(3), None), Err(ApiError::InvalidInput(_))));
+    assert!(matches!(sculpture.calculate_value(None, Some(3)), Err(ApiError::InvalidInput(_))));
+}
+
+#[test]
+fn mod_helpers() {
+    let item: ItemObject = serde_json::from_value(json!({
+        "id": "serration",
+        "slug": "serration",
+        "tags": ["mod", "rare", "primary"],
+        "rarity": "rare",
+        "maxRank": 10,
+    }))
+    .unwrap();
+    let serration = Item::new(&item).to_mod().unwrap();
+
+    assert_eq!(serration.max_rank(), 10);
+    assert_eq!(serration.max_charges(), None);
+    assert_eq!(serration.rarity(), Some("rare"));
+    assert!(serration.is_valid_rank(0));
+    assert!(serration.is_valid_rank(10));
+    assert!(!serration.is_valid_rank(11));
+}
+
+#[test]
+fn ducats_per_platinum() {
+    let part: ItemObject = serde_json::from_value(json!({
+        "id": "part",
+        "slug": "braton_prime_receiver",
+        "ducats": 45,
+    }))
+    .unwrap();
+    let part = Item::new(&part);
+
+    assert_eq!(part.ducats_per_platinum(5), Some(9.0));
+    assert_eq!(part.ducats_per_platinum(0), Some(f32::INFINITY));
+
+    let no_ducats: ItemObject = serde_json::from_value(json!({"id": "mod", "slug": "serration"})).unwrap();
+    assert_eq!(Item::new(&no_ducats).ducats_per_platinum(5), None);
+}
+
+#[test]
+fn classification() {
+    let item = |slug: &str, tags: &[&str]| {
+        let item: ItemObject = serde_json::from_value(json!({"id": slug, "slug": slug, "tags": tags})).unwrap();
+        Item::new(&item)
+    };
+
+    let set = item("mirage_prime_set", &["prime", "set", "warframe"]);
+    assert!(set.is_prime() && set.is_set());
+    assert!(!set.is_arcane() && !set.is_relic());
+
+    let arcane = item("arcane_energize", &["arcane_enhancement", "legendary"]);
+    assert!(arcane.is_arcane() && !arcane.is_prime());
+
+    let relic = item("lith_a1_relic", &["relic", "lith"]);
+    assert!(relic.is_relic() && !relic.is_set());
+}
+
+#[test]
+fn tags() {
+    let sculpture = anasa();
+
+    assert_eq!(sculpture.get_tags(), ["ayatan_sculpture"]);
+    assert!(sculpture.has_tag("ayatan_sculpture"));
+    assert!(!sculpture.has_tag("ayatan"));
+}
+
+#[test]
+fn localized_name_and_icon() {
+    let item: ItemObject = serde_json::from_value(json!({
+        "id": "serration",
+        "slug": "serration",
+        "i18n": {
+            "en": { "name": "Serration", "icon": "items/images/en/serration.png" },
+            "de": { "name": "Zacken", "icon": "items/images/de/serration.png" },
+        }
+    }))
+    .unwrap();
+    let item = Item::new(&item);
+
+    assert_eq!(item.get_name(), "Serration");
+    assert_eq!(item.get_localized_name("de"), "Zacken");
+    assert_eq!(item.get_localized_name("fr"), "Serration");
+    assert_eq!(
+        item.get_icon_url().as_deref(),
+        Some("https://warframe.market/static/assets/items/images/en/serration.png")
+    );
+    assert_eq!(
+        item.get_localized_icon_url("de").as_deref(),
+        Some("https://warframe.market/static/assets/items/images/de/serration.png")
+    );
+}
+
+#[test]
+fn order_request_bounds() {
+    let item: ItemObject = serde_json::from_value(json!({
+        "id": "serration",
+        "slug": "serration",
+        "maxRank": 10,
+    }))
+    .unwrap();
+    let serration = Item::new(&item);
+
+    let request = serration
+        .order_request(OrderType::Sell, 20, 1, OrderVariant { rank: Some(10), ..Default::default() })
+        .unwrap();
+    assert_eq!(request.item_id, "serration");
+    assert_eq!(request.rank, Some(10));
+
+    let too_high = serration.order_request(OrderType::Sell, 20, 1, OrderVariant { rank: Some(11), ..Default::default() });
+    assert!(matches!(too_high, Err(ApiError::InvalidInput(_))));
+
+    let no_charges = serration.order_request(OrderType::Sell, 20, 1, OrderVariant { charges: Some(1), ..Default::default() });
+    assert!(matches!(no_charges, Err(ApiError::InvalidInput(_))));
+
+    let free = serration.order_request(OrderType::Sell, 0, 1, OrderVariant::default());
+    assert!(matches!(free, Err(ApiError::InvalidInput(_))));
+}
+
+#[test]
+fn order_request_subtypes() {
+    let item: ItemObject = serde_json::from_value(json!({
+        "id": "relic",
+        "slug": "lith_a1_relic",
+        "subtypes": ["intact", "exceptional", "flawless", "radiant"],
+    }))
+    .unwrap();
+    let relic = Item::new(&item);
+    assert_eq!(relic.subtypes().unwrap().len(), 4);
+
+    let radiant = OrderVariant { subtype: Some("radiant".to_string()), ..Default::default() };
+    let request = relic.order_request(OrderType::Buy, 5, 1, radiant).unwrap();
+    assert_eq!(request.subtype.as_deref(), Some("radiant"));
+
+    let shiny = OrderVariant { subtype: Some("shiny".to_string()), ..Default::default() };
+    assert!(matches!(relic.order_request(OrderType::Buy, 5, 1, shiny), Err(ApiError::InvalidInput(_))));
+
+    let forma: ItemObject = serde_json::from_value(json!({"id": "forma", "slug": "forma_blueprint"})).unwrap();
+    let forma = Item::new(&forma);
+    assert!(forma.subtypes().is_none());
+    let intact = OrderVariant { subtype: Some("intact".to_string()), ..Default::default() };
+    assert!(matches!(forma.order_request(OrderType::Buy, 5, 1, intact), Err(ApiError::InvalidInput(_))));
+}
+
+#[test]
+fn relic_expected_ducats() {
+    let reward = |slug: &str, rarity: RelicRarity| RelicReward { item_slug: slug.to_string(), rarity };
+    let relic = Relic {
+        id: "lith_a1".to_string(),
+        slug: "lith_a1_relic".to_string(),
+        rewards: vec![
+            reward("braton_prime_receiver", RelicRarity::Rare),
+            reward("forma_blueprint", RelicRarity::Common),
+            reward("missing_part", RelicRarity::Uncommon),
+        ],
+    };
+
+    let ducats: HashMap<&str, u32> = HashMap::from([("braton_prime_receiver", 100)]);
+
+    assert_eq!(relic.expected_ducats(|slug| ducats.get(slug).copied()), 2.0);
+}
+
+#[test]
+fn item_round_trip() {
+    let original = json!({
+        "id": "serration",
+        "slug": "serration",
+        "tags": ["mod"],
+        "maxRank": 10,
+        "i18n": {
+            "en": { "name": "Serration", "icon": "items/images/en/serration.png" },
+        }
+    });
+    let item: ItemObject = serde_json::from_value(original.clone()).unwrap();
+
+    assert_eq!(serde_json::to_value(&item).unwrap(), original);
+}
+
+#[tokio::test]
+async fn items_snapshot() {
+    let snapshot = json!([
+        { "id": "serration", "slug": "serration", "maxRank": 10 },
+        { "id": "forma", "slug": "forma_blueprint" },
+    ])
+    .to_string();
+
+    let client = Client::new();
+    assert_eq!(client.items_to_json(), "[]");
+    assert!(matches!(client.load_items_from_json("{}"), Err(ApiError::Parse(_))));
+
+    client.load_items_from_json(&snapshot).unwrap();
+    let items = client.get_items().await.unwrap();
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[1].get_slug(), "forma_blueprint");
+
+    let restored = Client::new();
+    restored.load_items_from_json(&client.items_to_json()).unwrap();
+    assert_eq!(restored.items_to_json(), client.items_to_json());
+}
+
+#[tokio::test]
+async fn item_by_id() {
+    let client = Client::new();
+    client
+        .load_items_from_json(&json!([{ "id": "54aae292e7798909064f1575", "slug": "serration" }]).to_string())
+        .unwrap();
+
+    let item = client.get_item_by_id("54aae292e7798909064f1575").await.unwrap().unwrap();
+    assert_eq!(item.get_slug(), "serration");
+    assert!(client.get_item_by_id("serration").await.unwrap().is_none());
+}
+
+#[test]
+fn find_cached_items() {
+    let client = Client::new();
+    assert!(client.find_by_slug("serration").is_none());
+
+    client
+        .load_items_from_json(&json!([{ "id": "54aae292e7798909064f1575", "slug": "serration" }]).to_string())
+        .unwrap();
+    assert_eq!(client.find_by_slug("serration").unwrap().get_id(), "54aae292e7798909064f1575");
+    assert_eq!(client.find_by_id("54aae292e7798909064f1575").unwrap().get_slug(), "serration");
+
+    client.invalidate_items_cache();
+    assert!(client.find_by_slug("serration").is_none());
+    assert!(client.find_by_id("54aae292e7798909064f1575").is_none());
+}
+
+#[test]
+fn converted_items_are_clonable() {
+    let sculptures: Vec<Item<Sculpture>> = vec![anasa(), anasa()];
+    let copies = sculptures.clone();
+
+    assert_eq!(copies.len(), 2);
+    assert_eq!(
+        copies[0].calculate_value(None, None).unwrap(),
+        sculptures[0].calculate_value(None, None).unwrap()
+    );
+}
+
+#[test]
+fn item_equality() {
+    assert_eq!(anasa(), anasa());
+
+    let mut renamed = anasa().get_type();
+    renamed.slug = "ayatan_renamed_sculpture".to_string();
+    assert_ne!(anasa(), Item::new(&renamed).to_sculpture().unwrap());
+}
+
+#[tokio::test]
+async fn tradable_and_vaulted_items() {
+    let client = Client::new();
+    client
+        .load_items_from_json(
+            &json!([
+                { "id": "serration", "slug": "serration" },
+                { "id": "ash", "slug": "ash_prime_set", "vaulted": true },
+                { "id": "riven", "slug": "untradable", "tradable": false },
+            ])
+            .to_string(),
+        )
+        .unwrap();
+
+    let tradable = client.get_tradable_items().await.unwrap();
+    assert_eq!(tradable.len(), 1);
+    assert_eq!(tradable[0].get_slug(), "serration");
+
+    let vaulted = client.get_vaulted_items().await.unwrap();
+    assert_eq!(vaulted.len(), 1);
+    assert_eq!(vaulted[0].get_slug(), "ash_prime_set");
+}
+
+#[tokio::test]
+async fn rank_sculptures() {
+    let client = Client::new();
+    client
+        .load_items_from_json(
+            &json!([
+                { "id": "serration", "slug": "serration", "maxRank": 10 },
+                ayr(),
+                { "id": "anasa", "slug": "ayatan_anasa_sculpture", "maxAmberStars": 2, "maxCyanStars": 2, "baseEndo": 450, "endoMultiplier": 3.6 },
+                { "id": "broken", "slug": "ayatan_broken_sculpture", "baseEndo": 450, "endoMultiplier": 3.6 },
+            ])
+            .to_string(),
+        )
+        .unwrap();
+
+    let ranked = client.rank_sculptures_by_value().await.unwrap();
+    let slugs: Vec<String> = ranked.iter().map(|(sculpture, _)| sculpture.get_slug()).collect();
+
+    assert_eq!(slugs, ["ayatan_anasa_sculpture", "ayatan_ayr_sculpture"]);
+    assert_eq!(ranked[0].1, 3450);
+    assert_eq!(ranked[1].1, 1425);
+}
@@ -0,0 +1,160 @@
+use super::helpers::order;
+use crate::client::{Client, HttpResponse, HttpTransport};
+use crate::error::{ApiError, AuthError};
+use crate::types::filter::OrdersTopFilters;
+use crate::types::user::StatusType;
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use serde_json::{Value, json};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+const TEST_ITEM: &str = "yareli_prime_set";
+
+/// Answers requests whose path ends with a known route, everything else is a 404
+#[derive(Clone, Default)]
+pub(super) struct MockTransport {
+    routes: Vec<(String, StatusCode, String)>,
+    pub(super) requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockTransport {
+    pub(super) fn with_route(mut self, path: &str, data: Value) -> Self {
+        let body = json!({ "apiVersion": "0.0.0", "data": data }).to_string();
+        self.routes.push((path.to_string(), StatusCode::OK, body));
+        self
+    }
+
+    pub(super) fn with_status(mut self, path: &str, status: StatusCode) -> Self {
+        self.routes.push((path.to_string(), status, String::new()));
+        self
+    }
+}
+
+impl HttpTransport for MockTransport {
+    fn send(
+        &self,
+        request: reqwest::Request,
+    ) -> Pin<Box<dyn Future<Output = Result<HttpResponse, ApiError>> + Send + '_>> {
+        let path = request.url().path().to_string();
+        self.requests.lock().unwrap().push(path.clone());
+
+        let (status, body) = match self.routes.iter().find(|(route, _, _)| path.ends_with(route.as_str())) {
+            Some((_, status, body)) => (*status, body.clone()),
+            None => (StatusCode::NOT_FOUND, String::new()),
+        };
+
+        Box::pin(async move {
+            Ok(HttpResponse {
+                status,
+                headers: HeaderMap::new(),
+                body,
+            })
+        })
+    }
+}
+
+#[tokio::test]
+async fn mocked_orders_top_platform() {
+    let transport = MockTransport::default().with_route(
+        &format!("/orders/item/{}/top", TEST_ITEM),
+        json!({
+            "buy": [order("buy", 40).platform("ps4").json()],
+            "sell": [order("sell", 30).platform("xbox").json(), order("sell", 45).json()],
+        }),
+    );
+    let client = Client::new().with_transport(transport);
+
+    let filters = OrdersTopFilters {
+        platform: Some("PC".to_string()),
+        ..Default::default()
+    };
+    let top = client.get_orders_top_split(TEST_ITEM, Some(filters)).await.unwrap();
+    assert!(top.buy.is_empty());
+    assert_eq!(top.lowest_sell_price(false), Some(45));
+}
+
+#[tokio::test]
+async fn mocked_orders_top_user_activity() {
+    let transport = MockTransport::default().with_route(
+        &format!("/orders/item/{}/top", TEST_ITEM),
+        json!({
+            "buy": [order("buy", 40).status("offline").json()],
+            "sell": [
+                order("sell", 30).status("online").json(),
+                order("sell", 45).json(),
+            ],
+        }),
+    );
+    let client = Client::new().with_transport(transport);
+
+    let unfiltered = client
+        .get_orders_top_split(TEST_ITEM, Some(OrdersTopFilters::new()))
+        .await
+        .unwrap();
+    assert_eq!(unfiltered.buy.len() + unfiltered.sell.len(), 3);
+
+    let filters = OrdersTopFilters::new().with_user_activity(StatusType::InGame);
+    let top = client.get_orders_top_split(TEST_ITEM, Some(filters)).await.unwrap();
+    assert!(top.buy.is_empty());
+    assert_eq!(top.lowest_sell_price(false), Some(45));
+}
+
+#[tokio::test]
+async fn mocked_not_found() {
+    let client = Client::new().with_transport(MockTransport::default());
+
+    assert!(matches!(client.get_item(TEST_ITEM).await, Err(ApiError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn mocked_find_matches() {
+    let transport = MockTransport::default()
+        .with_route("/items", json!([{ "id": "item", "slug": TEST_ITEM }]))
+        .with_route(
+            &format!("/orders/item/{}", TEST_ITEM),
+            json!([
+                order("buy", 40).json(),
+                order("buy", 55).json(),
+                order("buy", 60).json(),
+                order("sell", 70).json(),
+            ]),
+        );
+    let client = Client::new().with_transport(transport);
+
+    let matches = client.find_matches(&order("sell", 50).build()).await.unwrap();
+
+    let prices: Vec<u32> = matches.iter().map(|o| o.get_platinum()).collect();
+    assert_eq!(prices, [60, 55]);
+}
+
+#[tokio::test]
+async fn mocked_get_relic() {
+    let transport = MockTransport::default()
+        .with_route("/item/lith_a1_relic", json!({ "id": "lith_a1", "slug": "lith_a1_relic", "tags": ["relic", "lith"] }))
+        .with_route(&format!("/item/{}", TEST_ITEM), json!({ "id": "yareli", "slug": TEST_ITEM, "tags": ["prime", "set"] }));
+    let client = Client::new().with_transport(transport);
+
+    let relic = client.get_relic("lith_a1_relic").await.unwrap();
+    assert_eq!(relic.id, "lith_a1");
+    assert_eq!(relic.slug, "lith_a1_relic");
+    assert!(relic.rewards.is_empty());
+
+    assert!(matches!(client.get_relic(TEST_ITEM).await, Err(ApiError::InvalidInput(_))));
+}
+
+#[tokio::test]
+async fn mocked_login_with_token() {
+    let rejecting = |status| MockTransport::default().with_status("/me", status).with_status("/orders/my", status);
+
+    let expired = Client::new().with_transport(rejecting(StatusCode::UNAUTHORIZED));
+    let result = expired.login_with_token("token", "device").await;
+    assert!(matches!(result, Err(AuthError::TokenExpired)));
+
+    let unavailable = Client::new()
+        .with_retries(0)
+        .with_transport(rejecting(StatusCode::INTERNAL_SERVER_ERROR));
+    let result = unavailable.login_with_token("token", "device").await;
+    assert!(matches!(result, Err(AuthError::Api(ApiError::Http { status: 500, .. }))));
+}
@@ -1,4 +1,15 @@
+mod auction;
 mod authed_client;
 mod client;
+mod error;
+mod filter;
+mod helpers;
+mod item;
+mod mock;
+mod order;
+mod pricing;
+mod riven;
+mod transaction;
+mod user;
 mod ws;
 mod ratelimit;
\ No newline at end of file
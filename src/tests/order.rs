@@ -0,0 +1,132 @@
+use super::helpers::order;
+use crate::client::{Order, TopOrders};
+use crate::types::item::{Order as OrderItem, OrderType, OrderWithUser};
+use serde_json::json;
+
+#[test]
+fn top_orders_edge_prices() {
+    let top = TopOrders {
+        buy: vec![
+            order("buy", 20).status("offline").build(),
+            order("buy", 15).build(),
+            order("buy", 40).visible(false).build(),
+        ],
+        sell: vec![
+            order("sell", 25).status("online").build(),
+            order("sell", 22).status("offline").build(),
+            order("sell", 5).visible(false).status("online").build(),
+        ],
+    };
+
+    assert_eq!(top.highest_buy_price(false), Some(20));
+    assert_eq!(top.highest_buy_price(true), Some(15));
+    assert_eq!(top.lowest_sell_price(false), Some(22));
+    assert_eq!(top.lowest_sell_price(true), Some(25));
+}
+
+#[test]
+fn top_orders_empty() {
+    let top = TopOrders {
+        buy: Vec::new(),
+        sell: vec![order("sell", 5).visible(false).status("online").build()],
+    };
+
+    assert!(top.highest_buy(false).is_none());
+    assert!(top.lowest_sell(false).is_none());
+}
+
+#[test]
+fn malformed_timestamp_is_an_error() {
+    let order = serde_json::from_value::<OrderWithUser>(json!({
+        "id": "order",
+        "type": "sell",
+        "platinum": 10,
+        "quantity": 1,
+        "visible": true,
+        "itemId": "item",
+        "createdAt": "yesterday",
+        "updatedAt": "2025-06-01T12:00:00Z",
+        "user": {
+            "id": "user",
+            "ingameName": "Trader",
+            "reputation": 10,
+        },
+    }));
+
+    assert!(order.is_err());
+}
+
+#[test]
+fn order_type_helpers() {
+    assert_eq!(OrderType::Buy.opposite(), OrderType::Sell);
+    assert_eq!(OrderType::Sell.opposite(), OrderType::Buy);
+    assert_eq!(OrderType::Buy.to_string(), "buy");
+    assert_eq!(OrderType::Sell.as_str(), "sell");
+}
+
+#[test]
+fn order_accessors() {
+    let order: OrderItem = serde_json::from_value(json!({
+        "id": "order",
+        "type": "sell",
+        "platinum": 30,
+        "quantity": 4,
+        "perTrade": 2,
+        "subtype": "radiant",
+        "visible": true,
+        "itemId": "lith_a1_relic",
+        "createdAt": "2025-06-01T12:00:00Z",
+        "updatedAt": "2025-06-01T12:00:00Z",
+    }))
+    .unwrap();
+    let order = Order::new(&order);
+
+    assert_eq!(order.get_quantity(), 4);
+    assert_eq!(order.total_value(), 120);
+    assert_eq!(order.total_value_u64(), 120);
+    assert_eq!(order.get_per_trade(), Some(2));
+    assert_eq!(order.get_subtype(), Some("radiant"));
+    assert_eq!(order.get_rank(), None);
+    assert_eq!(order.get_charges(), None);
+}
+
+#[test]
+fn order_debug() {
+    let order = order("sell", 15).build();
+    let debug = format!("{:?}", order);
+
+    assert!(debug.contains("platinum: 15"));
+    assert!(debug.contains("InGame"));
+}
+
+#[test]
+fn order_equality() {
+    let first = order("sell", 15).build();
+    let without_user = Order::new(&first.get_type());
+
+    // The attached user is not part of the order
+    assert_eq!(first, without_user);
+    assert_ne!(first, order("sell", 16).build());
+
+    let mut changed = first.get_type();
+    changed.visible = false;
+    assert_ne!(first.get_type(), changed);
+}
+
+#[test]
+fn order_diff() {
+    let current = order("sell", 15).build();
+    assert!(current.diff(&current).is_empty());
+
+    let mut wanted = current.get_type();
+    wanted.platinum = 12;
+    wanted.visible = false;
+    wanted.rank = Some(3);
+
+    let changes = current.diff(&Order::new(&wanted));
+    assert_eq!(changes.platinum, Some(12));
+    assert_eq!(changes.visible, Some(false));
+    assert_eq!(changes.rank, Some(3));
+    assert_eq!(changes.quantity, None);
+    assert_eq!(changes.per_trade, None);
+}
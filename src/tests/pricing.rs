@@ -0,0 +1,31 @@
+use super::helpers::order;
+use crate::client::{Order, Unowned, price_with_confidence};
+
+fn sell_order(platinum: u32) -> Order<Unowned> {
+    order("sell", platinum).build()
+}
+
+#[test]
+fn no_orders_no_estimate() {
+    assert_eq!(price_with_confidence(&[], None), None);
+}
+
+#[test]
+fn outliers_are_ignored() {
+    let orders: Vec<Order<Unowned>> = [10, 11, 12, 12, 13, 500].map(sell_order).to_vec();
+
+    let estimate = price_with_confidence(&orders, None).unwrap();
+    assert_eq!(estimate.price, 12.0);
+    assert!(estimate.low <= estimate.price && estimate.price <= estimate.high);
+}
+
+#[test]
+fn confidence_grows_with_orders() {
+    let few: Vec<Order<Unowned>> = [10, 12].map(sell_order).to_vec();
+    let many: Vec<Order<Unowned>> = [10, 12].repeat(10).into_iter().map(sell_order).collect();
+
+    let few = price_with_confidence(&few, None).unwrap();
+    let many = price_with_confidence(&many, None).unwrap();
+    assert!(many.confidence > few.confidence);
+    assert!(many.high - many.low < few.high - few.low);
+}
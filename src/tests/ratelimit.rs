@@ -1,22 +1,41 @@
+use std::num::NonZeroU32;
+use std::time::{Duration, Instant};
 use std::sync::Arc;
 use futures_util::future::join_all;
-use tokio::sync::Mutex;
 use tokio::task::JoinHandle;
 use crate::Client;
+use super::mock::MockTransport;
+use serde_json::json;
 
 #[tokio::test]
 async fn test_orders() {
-    let client: Arc<Mutex<Client>> = Mutex::new(Client::new()).into();
+    let client: Arc<Client> = Client::new().into();
     
     let mut join_handles: Vec<JoinHandle<()>> = Vec::new();
     for _ in 0..10 {
         let client_clone = client.clone();
         let task = tokio::spawn(async move {
-            let ret = client_clone.lock().await.get_items().await;
+            let ret = client_clone.get_items().await;
             assert!(ret.is_ok());
         });
         join_handles.push(task);
     }
     
     join_all(join_handles).await;
-}
\ No newline at end of file
+}
+
+#[tokio::test]
+async fn test_custom_rate_limit() {
+    let transport = MockTransport::default().with_route("/item/mirage_prime_set", json!({ "id": "mirage", "slug": "mirage_prime_set" }));
+    let client = Client::new()
+        .with_rate_limit(NonZeroU32::new(1).unwrap())
+        .with_transport(transport);
+
+    let start = Instant::now();
+    for _ in 0..3 {
+        assert!(client.get_item("mirage_prime_set").await.is_ok());
+    }
+
+    // The first request passes instantly, the following two wait a second each
+    assert!(start.elapsed() >= Duration::from_secs(2));
+}
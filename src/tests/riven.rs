@@ -0,0 +1,27 @@
+use crate::client::Riven;
+use crate::types::riven::Riven as RivenObject;
+use serde_json::json;
+
+#[test]
+fn wiki_link() {
+    let riven: RivenObject = serde_json::from_value(json!({
+        "id": "soma",
+        "slug": "soma",
+        "rivenType": "rifle",
+        "disposition": 0.5,
+        "reqMasteryRank": 6,
+        "i18n": {
+            "en": {
+                "name": "Soma",
+                "wikiLink": "https://warframe.fandom.com/wiki/Soma",
+                "icon": "items/images/en/soma.png",
+                "thumb": "items/images/en/thumbs/soma.png",
+            }
+        }
+    }))
+    .unwrap();
+    let riven = Riven::new(&riven);
+
+    assert_eq!(riven.get_wiki_link("en").as_deref(), Some("https://warframe.fandom.com/wiki/Soma"));
+    assert_eq!(riven.get_wiki_link("de").as_deref(), Some("https://warframe.fandom.com/wiki/Soma"));
+}
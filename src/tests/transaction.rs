@@ -0,0 +1,20 @@
+use crate::types::item::OrderType;
+use crate::types::transaction::Transaction;
+use serde_json::json;
+
+#[test]
+fn transaction_order_type() {
+    let transaction: Transaction = serde_json::from_value(json!({
+        "id": "transaction",
+        "type": "sell",
+        "originId": "order",
+        "platinum": 20,
+        "quantity": 1,
+        "createdAt": "2025-06-01T12:00:00Z",
+        "updatedAt": "2025-06-01T12:00:00Z",
+        "item": { "id": "item" },
+    }))
+    .unwrap();
+
+    assert_eq!(transaction.order_type, OrderType::Sell);
+}
@@ -0,0 +1,46 @@
+use crate::types::user::{FullUser, StatusType};
+use serde_json::json;
+
+#[test]
+fn status_type_round_trip() {
+    let invisible: StatusType = serde_json::from_value(json!("invisible")).unwrap();
+    assert_eq!(invisible, StatusType::Invisible);
+    assert_eq!(serde_json::to_value(&invisible).unwrap(), json!("invisible"));
+
+    let in_game: StatusType = serde_json::from_value(json!("in_game")).unwrap();
+    assert_eq!(in_game, StatusType::InGame);
+    assert_eq!(serde_json::to_value(&in_game).unwrap(), json!("ingame"));
+}
+
+#[test]
+fn full_user_profile_fields() {
+    let user: FullUser = serde_json::from_value(json!({
+        "id": "user",
+        "ingameName": "Trader",
+        "reputation": 10,
+        "platform": "pc",
+        "unreadNotifications": 0,
+        "avatar": "user/avatar/user.png",
+        "locale": "en",
+        "crossplay": true,
+        "verification": true,
+    }))
+    .unwrap();
+
+    assert_eq!(user.avatar.as_deref(), Some("user/avatar/user.png"));
+    assert_eq!(user.locale.as_deref(), Some("en"));
+    assert_eq!(user.crossplay, Some(true));
+    assert_eq!(user.verification, Some(true));
+
+    let legacy: FullUser = serde_json::from_value(json!({
+        "id": "user",
+        "ingame_name": "Trader",
+        "reputation": 10,
+        "platform": "pc",
+        "unread_messages": 0,
+    }))
+    .unwrap();
+
+    assert_eq!(legacy.avatar, None);
+    assert_eq!(legacy.crossplay, None);
+}
@@ -1,5 +1,7 @@
 use crate::Client;
-use crate::client::ws::WsMessage;
+use crate::client::ws::{ConnectionState, WsClientBuilder, WsMessage};
+use crate::error::WsError;
+use crate::types::user::StatusType;
 use dotenv::dotenv;
 use serde_json::json;
 use std::env;
@@ -71,5 +73,304 @@ async fn test_connection() {
     })
     .await;
 
-    assert!(received_messages.lock().unwrap().len() > 0);
+    assert!(!received_messages.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_close() {
+    let disconnects: Arc<Mutex<Vec<WsMessage>>> = Arc::new(Mutex::new(Vec::new()));
+    let disconnects_clone = disconnects.clone();
+
+    dotenv().ok();
+
+    let user = env::var("TEST_USER").expect("TEST_USER must be set in .env for integration tests");
+    let pass = env::var("TEST_PASS").expect("TEST_PASS must be set in .env for integration tests");
+
+    let client = { Client::new().login(&user, &pass, "dev").await.unwrap() };
+
+    let ws_client = client
+        .create_websocket()
+        .register_callback("internal/disconnected", move |msg, _, _| {
+            disconnects_clone.lock().unwrap().push(msg.clone());
+            Ok(())
+        })
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+
+    let sender = ws_client.get_sender().unwrap();
+    ws_client.close().await;
+
+    let disconnects = disconnects.lock().unwrap();
+    assert_eq!(disconnects.len(), 1);
+    assert_eq!(disconnects[0].payload, Some(json!({"reason": "Client closed"})));
+    assert!(matches!(
+        sender.send_request("@wfm|cmd/status/set", json!({})),
+        Err(WsError::NotConnected)
+    ));
+}
+
+#[tokio::test]
+async fn test_connection_change() {
+    let states: Arc<Mutex<Vec<ConnectionState>>> = Arc::new(Mutex::new(Vec::new()));
+    let states_clone = states.clone();
+
+    dotenv().ok();
+
+    let user = env::var("TEST_USER").expect("TEST_USER must be set in .env for integration tests");
+    let pass = env::var("TEST_PASS").expect("TEST_PASS must be set in .env for integration tests");
+
+    let client = { Client::new().login(&user, &pass, "dev").await.unwrap() };
+
+    let ws_client = client
+        .create_websocket()
+        .on_connection_change(move |state| states_clone.lock().unwrap().push(state))
+        .build()
+        .await
+        .unwrap();
+
+    ws_client.close().await;
+
+    assert_eq!(*states.lock().unwrap(), vec![
+        ConnectionState::Connecting,
+        ConnectionState::Connected,
+        ConnectionState::Disconnected("Client closed".to_string()),
+    ]);
+}
+
+#[tokio::test]
+async fn test_request() {
+    dotenv().ok();
+
+    let user = env::var("TEST_USER").expect("TEST_USER must be set in .env for integration tests");
+    let pass = env::var("TEST_PASS").expect("TEST_PASS must be set in .env for integration tests");
+
+    let client = { Client::new().login(&user, &pass, "dev").await.unwrap() };
+
+    let ws_client = client.create_websocket().build().await.unwrap();
+
+    let response = ws_client
+        .request("@wfm|cmd/status/set", json!({"status": "invisible"}))
+        .await
+        .unwrap();
+    assert_eq!(response.route, "@wfm|cmd/status/set:ok");
+}
+
+#[tokio::test]
+async fn test_set_status() {
+    let acks: Arc<Mutex<Vec<WsMessage>>> = Arc::new(Mutex::new(Vec::new()));
+    let acks_clone = acks.clone();
+
+    dotenv().ok();
+
+    let user = env::var("TEST_USER").expect("TEST_USER must be set in .env for integration tests");
+    let pass = env::var("TEST_PASS").expect("TEST_PASS must be set in .env for integration tests");
+
+    let client = { Client::new().login(&user, &pass, "dev").await.unwrap() };
+
+    let ws_client = client
+        .create_websocket()
+        .register_callback("cmd/status/set:ok", move |msg, _, _| {
+            acks_clone.lock().unwrap().push(msg.clone());
+            Ok(())
+        })
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+
+    let id = ws_client.set_status(StatusType::Online).unwrap();
+
+    let _ = timeout(Duration::from_secs(5), async {
+        while acks.lock().unwrap().is_empty() {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await;
+
+    assert_eq!(acks.lock().unwrap()[0].ref_id, Some(id));
+}
+
+#[tokio::test]
+async fn test_subscribe_item() {
+    dotenv().ok();
+
+    let user = env::var("TEST_USER").expect("TEST_USER must be set in .env for integration tests");
+    let pass = env::var("TEST_PASS").expect("TEST_PASS must be set in .env for integration tests");
+
+    let client = { Client::new().login(&user, &pass, "dev").await.unwrap() };
+
+    let ws_client = client
+        .create_websocket()
+        .on_new_order(|order, _| {
+            println!("New order: {}p", order.get_platinum());
+            Ok(())
+        })
+        .unwrap()
+        .build()
+        .await
+        .unwrap();
+
+    let _ = ws_client.subscribe_item("yareli_prime_set").unwrap();
+
+    // The typed callback takes up the route
+    assert!(
+        client
+            .create_websocket()
+            .on_new_order(|_, _| Ok(()))
+            .unwrap()
+            .register_callback("event/subscriptions/newOrder", |_, _, _| Ok(()))
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_private_message() {
+    dotenv().ok();
+
+    let user = env::var("TEST_USER").expect("TEST_USER must be set in .env for integration tests");
+    let pass = env::var("TEST_PASS").expect("TEST_PASS must be set in .env for integration tests");
+
+    let client = { Client::new().login(&user, &pass, "dev").await.unwrap() };
+    let user_id = client.user.clone().unwrap().id;
+
+    let ws_client = client.create_websocket().build().await.unwrap();
+
+    assert!(matches!(
+        ws_client.send_private_message(&user_id, " "),
+        Err(WsError::InvalidMessage)
+    ));
+    assert!(matches!(
+        ws_client.send_request("@wfm|cmd/auth/signIn", json!({})),
+        Err(WsError::ReservedPath(_))
+    ));
+    assert!(matches!(
+        ws_client.send_request("@internal|internal/connected", json!({})),
+        Err(WsError::ReservedPath(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_unhandled() {
+    let unhandled: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
+    let unhandled_clone = unhandled.clone();
+
+    dotenv().ok();
+
+    let user = env::var("TEST_USER").expect("TEST_USER must be set in .env for integration tests");
+    let pass = env::var("TEST_PASS").expect("TEST_PASS must be set in .env for integration tests");
+
+    let client = { Client::new().login(&user, &pass, "dev").await.unwrap() };
+
+    let ws_client = client
+        .create_websocket()
+        .on_unhandled(move |msg, _| unhandled_clone.lock().unwrap().push(msg.route.clone()))
+        .build()
+        .await
+        .unwrap();
+
+    let _ = ws_client.set_status(StatusType::Online).unwrap();
+
+    let _ = timeout(Duration::from_secs(5), async {
+        while !unhandled.lock().unwrap().iter().any(|r| r == "@wfm|cmd/status/set:ok") {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await;
+
+    assert!(unhandled.lock().unwrap().iter().any(|r| r == "@wfm|cmd/status/set:ok"));
+}
+
+#[tokio::test]
+async fn test_connected_after_build() {
+    dotenv().ok();
+
+    let user = env::var("TEST_USER").expect("TEST_USER must be set in .env for integration tests");
+    let pass = env::var("TEST_PASS").expect("TEST_PASS must be set in .env for integration tests");
+
+    let client = { Client::new().login(&user, &pass, "dev").await.unwrap() };
+
+    let ws_client = client.create_websocket().build().await.unwrap();
+
+    // No need to wait for the connection before sending
+    assert!(ws_client.get_sender().is_some());
+    ws_client.set_status(StatusType::Online).unwrap();
+}
+
+#[tokio::test]
+async fn test_is_connected() {
+    dotenv().ok();
+
+    let user = env::var("TEST_USER").expect("TEST_USER must be set in .env for integration tests");
+    let pass = env::var("TEST_PASS").expect("TEST_PASS must be set in .env for integration tests");
+
+    let client = { Client::new().login(&user, &pass, "dev").await.unwrap() };
+
+    let ws_client = client.create_websocket().build().await.unwrap();
+
+    assert!(ws_client.is_connected());
+    assert_eq!(ws_client.connection_state(), ConnectionState::Connected);
+}
+
+#[tokio::test]
+async fn test_heartbeat() {
+    let states: Arc<Mutex<Vec<ConnectionState>>> = Arc::new(Mutex::new(Vec::new()));
+    let states_clone = states.clone();
+
+    dotenv().ok();
+
+    let user = env::var("TEST_USER").expect("TEST_USER must be set in .env for integration tests");
+    let pass = env::var("TEST_PASS").expect("TEST_PASS must be set in .env for integration tests");
+
+    let client = { Client::new().login(&user, &pass, "dev").await.unwrap() };
+
+    let ws_client = client
+        .create_websocket()
+        .with_heartbeat(Duration::from_millis(100), Duration::from_secs(2))
+        .on_connection_change(move |state| states_clone.lock().unwrap().push(state))
+        .build()
+        .await
+        .unwrap();
+
+    // Pongs keep the connection alive
+    sleep(Duration::from_secs(3)).await;
+    assert!(ws_client.is_connected());
+    assert_eq!(*states.lock().unwrap(), vec![ConnectionState::Connecting, ConnectionState::Connected]);
+}
+
+#[tokio::test]
+async fn test_request_timeout() {
+    dotenv().ok();
+
+    let user = env::var("TEST_USER").expect("TEST_USER must be set in .env for integration tests");
+    let pass = env::var("TEST_PASS").expect("TEST_PASS must be set in .env for integration tests");
+
+    let client = { Client::new().login(&user, &pass, "dev").await.unwrap() };
+
+    let ws_client = client.create_websocket().build().await.unwrap();
+
+    let response = ws_client
+        .request_with_timeout(
+            "@wfm|cmd/status/set",
+            json!({"status": "invisible"}),
+            Duration::from_nanos(1),
+        )
+        .await;
+    assert!(matches!(response, Err(WsError::Timeout)));
+}
+
+#[tokio::test]
+async fn test_connect_timeout() {
+    // Nothing listens on the port once the listener is dropped
+    let port = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+
+    let build = WsClientBuilder::new("token".to_string(), "device".to_string())
+        .with_url(&format!("ws://127.0.0.1:{}", port))
+        .with_reconnect_backoff(Duration::from_millis(50), Duration::from_millis(50))
+        .with_connect_timeout(Duration::from_millis(500))
+        .build();
+
+    let result = timeout(Duration::from_secs(5), build).await.expect("build should give up instead of hanging");
+    assert!(matches!(result, Err(WsError::Timeout)));
 }
@@ -0,0 +1,30 @@
+use crate::client::ws::{OnlineCount, WsMessage};
+use crate::error::WsError;
+use serde_json::json;
+
+#[test]
+fn test_parse_payload() {
+    let msg = WsMessage::new(
+        "@wfm|event/reports/online",
+        Some(json!({"connections": 120, "authorizedUsers": 80})),
+    );
+
+    let count: OnlineCount = msg.parse_payload().unwrap();
+    assert_eq!(count.connections, 120);
+    assert_eq!(count.authorized_users, 80);
+}
+
+#[test]
+fn test_parse_payload_mismatch() {
+    let msg = WsMessage::new("@wfm|event/reports/online", Some(json!({"connections": "many"})));
+
+    let result = msg.parse_payload::<OnlineCount>();
+    assert!(matches!(result, Err(WsError::InvalidPayload(_))));
+}
+
+#[test]
+fn test_parse_missing_payload() {
+    let msg = WsMessage::new("@wfm|event/reports/online", None);
+
+    assert_eq!(msg.parse_payload::<Option<OnlineCount>>().unwrap(), None);
+}
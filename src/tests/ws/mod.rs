@@ -1,2 +1,3 @@
+mod message;
 mod router;
 mod builder;
\ No newline at end of file
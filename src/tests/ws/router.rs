@@ -3,9 +3,12 @@ use crate::error::WsError;
 
 #[test]
 fn test_route_parsing_with_parameter() {
-    let route = Route::parse("@wfm|subscribe/newOrders").unwrap();
+    let route = Route::parse("@wfm|cmd/subscribe/newOrders:ok").unwrap();
     assert_eq!(route.protocol, "@wfm");
-    assert_eq!(route.path, "subscribe/newOrders");
+    assert_eq!(route.path, "cmd/subscribe/newOrders");
+    assert_eq!(route.parameter.as_deref(), Some("ok"));
+    assert_eq!(route.base_path(), "cmd/subscribe/newOrders");
+    assert_eq!(route.full_path(), "cmd/subscribe/newOrders:ok");
 }
 
 #[test]
@@ -13,6 +16,19 @@ fn test_route_parsing_without_parameter() {
     let route = Route::parse("@wfm|subscribe/newOrders").unwrap();
     assert_eq!(route.protocol, "@wfm");
     assert_eq!(route.path, "subscribe/newOrders");
+    assert_eq!(route.parameter, None);
+    assert_eq!(route.base_path(), route.full_path());
+}
+
+#[test]
+fn test_route_parameter_splits_on_first_colon() {
+    let route = Route::parse("@wfm|cmd/status:error:timeout").unwrap();
+    assert_eq!(route.path, "cmd/status");
+    assert_eq!(route.parameter.as_deref(), Some("error:timeout"));
+
+    let route = Route::parse("@wfm|cmd/status:").unwrap();
+    assert_eq!(route.parameter.as_deref(), Some(""));
+    assert_eq!(route.full_path(), "cmd/status:");
 }
 
 #[test]
@@ -25,6 +41,13 @@ fn test_route_to_string() {
     assert_eq!(route_with_param.to_string(), "@wfm|cmd/subscribe/newOrders");
 }
 
+#[test]
+fn test_route_round_trip() {
+    for raw in ["@wfm|cmd/subscribe/newOrders:ok", "@wfm|event/user/login", "@internal|connection"] {
+        assert_eq!(Route::parse(raw).unwrap().to_string(), raw);
+    }
+}
+
 #[test]
 fn test_route_parsing_invalid_format() {
     let result = Route::parse("invalid_route_format");
@@ -44,3 +67,16 @@ fn test_route_to_string_without_parameter() {
     };
     assert_eq!(route.to_string(), "@wfm|event/user/login");
 }
+
+#[test]
+fn test_route_matches() {
+    let route = Route::parse("@wfm|cmd/subscribe/newOrders:ok").unwrap();
+
+    assert!(route.matches("cmd/subscribe/newOrders:ok"));
+    assert!(route.matches("cmd/subscribe/newOrders"));
+    assert!(route.matches("cmd/subscribe/*"));
+    assert!(route.matches("cmd/subscribe/newOrders:*"));
+    assert!(route.matches("*"));
+    assert!(!route.matches("cmd/subscribe/newOrders:error"));
+    assert!(!route.matches("cmd/status/*"));
+}
@@ -0,0 +1,57 @@
+use crate::types::riven::Polarity;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct Auction {
+    pub id: String,
+    pub starting_price: u32,
+    pub buyout_price: Option<u32>,
+    pub top_bid: Option<u32>,
+    pub minimal_reputation: i32,
+    #[serde(default)]
+    pub note: String,
+    pub private: bool,
+    pub visible: bool,
+    pub closed: bool,
+    pub is_direct_sell: bool,
+    pub platform: String,
+    pub created: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+    pub item: AuctionItem,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct AuctionItem {
+    #[serde(rename = "type")]
+    pub item_type: String,
+    pub weapon_url_name: String,
+    pub name: String,
+    pub attributes: Vec<RivenAttributeValue>,
+    pub mastery_level: u8,
+    pub re_rolls: u32,
+    pub polarity: Polarity,
+    pub mod_rank: u8,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct RivenAttributeValue {
+    pub url_name: String,
+    pub positive: bool,
+    pub value: f64,
+}
+
+impl RivenAttributeValue {
+    pub fn new(url_name: &str, positive: bool, value: f64) -> Self {
+        RivenAttributeValue {
+            url_name: url_name.to_string(),
+            positive,
+            value,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct AuctionPayload {
+    pub auction: Auction,
+}
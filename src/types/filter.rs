@@ -1,7 +1,16 @@
 use serde::Serialize;
-use crate::types::user::StatusType;
+use crate::error::ApiError;
+use crate::types::item::{Item, OrderType, OrderWithUser};
+use crate::types::user::{MinimalUser, StatusType};
 
-#[derive(Clone, Default, Serialize)]
+/**
+Filters for [`get_orders_top`][crate::Client::get_orders_top], every field that is set has to match
+
+The rank, charges, stars and subtype filters are sent to WFM. The top orders endpoint has no
+filter on the users status or platform, so `user_activity` and `platform` are applied client-side
+to the returned orders, leaving them unset keeps every order
+*/
+#[derive(Clone, Default, Serialize, Debug)]
 pub struct OrdersTopFilters {
     pub rank: Option<u32>,
     #[serde(rename = "rankLt")]
@@ -23,6 +32,233 @@ pub struct OrdersTopFilters {
     
     pub subtype: Option<String>,
     
+    /// Only keep orders from users with this status, applied client-side
     #[serde(skip)]
     pub user_activity: Option<StatusType>,
-}
\ No newline at end of file
+
+    /// Only keep orders from users on this platform (e.g. `pc`), applied client-side
+    #[serde(skip)]
+    pub platform: Option<String>,
+}
+
+impl OrdersTopFilters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only orders of exactly this rank
+    pub fn with_rank(mut self, rank: u32) -> Self {
+        self.rank = Some(rank);
+        self
+    }
+
+    /// Only orders up to and including this rank
+    pub fn with_max_rank(mut self, rank: u32) -> Self {
+        self.rank_lt = Some(rank.saturating_add(1));
+        self
+    }
+
+    /// Only orders with exactly this many charges left
+    pub fn with_charges(mut self, charges: u32) -> Self {
+        self.charges = Some(charges);
+        self
+    }
+
+    /// Only orders with exactly this many amber stars installed
+    pub fn with_amber_stars(mut self, stars: u32) -> Self {
+        self.amber_stars = Some(stars);
+        self
+    }
+
+    /// Only orders with exactly this many cyan stars installed
+    pub fn with_cyan_stars(mut self, stars: u32) -> Self {
+        self.cyan_stars = Some(stars);
+        self
+    }
+
+    pub fn with_subtype(mut self, subtype: &str) -> Self {
+        self.subtype = Some(subtype.to_string());
+        self
+    }
+
+    /// Only orders from users with this status, applied client-side
+    pub fn with_user_activity(mut self, status: StatusType) -> Self {
+        self.user_activity = Some(status);
+        self
+    }
+
+    /// Only orders from users on this platform, applied client-side
+    pub fn with_platform(mut self, platform: &str) -> Self {
+        self.platform = Some(platform.to_string());
+        self
+    }
+
+    /**
+    Check the filters for contradicting combinations, done before requesting the top orders
+
+    # Arguments
+    - `item`: The item the filters are used for, if known, to reject filters the item can't match
+      (e.g. stars on an item which isn't a sculpture)
+
+    # Returns
+    - `ApiError::InvalidInput` describing the first invalid filter
+    */
+    pub fn validate(&self, item: Option<&Item>) -> Result<(), ApiError> {
+        let exclusive = [
+            ("rank", self.rank, self.rank_lt),
+            ("charges", self.charges, self.charges_lt),
+            ("amber_stars", self.amber_stars, self.amber_stars_lt),
+            ("cyan_stars", self.cyan_stars, self.cyan_stars_lt),
+        ];
+
+        for (name, exact, lt) in exclusive {
+            if exact.is_some() && lt.is_some() {
+                return Err(ApiError::InvalidInput(format!(
+                    "{} and {}_lt are mutually exclusive", name, name
+                )));
+            }
+        }
+
+        let Some(item) = item else {
+            return Ok(());
+        };
+
+        if (self.rank.is_some() || self.rank_lt.is_some()) && item.max_rank.is_none() {
+            return Err(ApiError::InvalidInput(format!("{} has no ranks", item.slug)));
+        }
+
+        if (self.charges.is_some() || self.charges_lt.is_some()) && item.max_charges.is_none() {
+            return Err(ApiError::InvalidInput(format!("{} has no charges", item.slug)));
+        }
+
+        let has_star_filter = self.amber_stars.is_some()
+            || self.amber_stars_lt.is_some()
+            || self.cyan_stars.is_some()
+            || self.cyan_stars_lt.is_some();
+        if has_star_filter && (item.base_endo.is_none() || item.endo_multiplier.is_none()) {
+            return Err(ApiError::InvalidInput(format!("{} is not a sculpture", item.slug)));
+        }
+
+        if let Some(subtype) = &self.subtype
+            && !item.subtypes.as_ref().is_some_and(|subtypes| subtypes.contains(subtype))
+        {
+            return Err(ApiError::InvalidInput(format!(
+                "{} is not a subtype of {}", subtype, item.slug
+            )));
+        }
+
+        Ok(())
+    }
+
+    /**
+    Whether the user who placed an order passes the client-side filters
+
+    # Arguments
+    - `user`: The user who placed the order
+
+    # Returns
+    - `false` if a platform is required and the users platform is unknown
+    */
+    pub fn matches_user(&self, user: &MinimalUser) -> bool {
+        self.user_activity.as_ref().is_none_or(|s| user.status_type == *s)
+            && matches_platform(self.platform.as_deref(), user)
+    }
+}
+
+/**
+Client-side filters for [`get_orders_filtered`][crate::Client::get_orders_filtered],
+every field that is set has to match
+
+- `order_type`: Only keep buy or sell orders
+- `online_only`: Only keep orders from users who are online or in game
+- `platform`: Only keep orders from users on this platform (e.g. `pc`),
+  orders from users whose platform is unknown are skipped
+*/
+#[derive(Clone, Default, Debug)]
+pub struct OrdersFilters {
+    pub order_type: Option<OrderType>,
+    pub online_only: bool,
+    pub platform: Option<String>,
+}
+
+impl OrdersFilters {
+    pub fn matches(&self, order: &OrderWithUser) -> bool {
+        if self
+            .order_type
+            .is_some_and(|order_type| order.order.order_type != order_type)
+        {
+            return false;
+        }
+
+        if self.online_only
+            && !matches!(order.user.status_type, StatusType::Online | StatusType::InGame)
+        {
+            return false;
+        }
+
+        matches_platform(self.platform.as_deref(), &order.user)
+    }
+}
+
+fn matches_platform(platform: Option<&str>, user: &MinimalUser) -> bool {
+    platform.is_none_or(|platform| {
+        user.platform
+            .as_deref()
+            .is_some_and(|p| p.eq_ignore_ascii_case(platform))
+    })
+}
+
+/**
+Client-side item search, every field that is set has to match
+
+- `name_contains`: Case-insensitive substring of the english item name
+- `tags`: Tags the item must all have
+- `tradable`: Items missing the field are treated as tradable
+- `vaulted`: Items missing the field are treated as not vaulted
+- `max_mastery_rank`: Items without a mastery requirement always match
+*/
+#[derive(Clone, Default, Debug)]
+pub struct ItemSearch {
+    pub name_contains: Option<String>,
+    pub tags: Option<Vec<String>>,
+    pub tradable: Option<bool>,
+    pub vaulted: Option<bool>,
+    pub max_mastery_rank: Option<u32>,
+}
+
+impl ItemSearch {
+    pub fn matches(&self, item: &Item) -> bool {
+        if let Some(name) = &self.name_contains {
+            let item_name = item.i18n.get("en").map(|t| t.name.to_lowercase());
+            if !item_name.is_some_and(|n| n.contains(&name.to_lowercase())) {
+                return false;
+            }
+        }
+
+        if let Some(tags) = &self.tags
+            && !tags.iter().all(|tag| item.tags.contains(tag))
+        {
+            return false;
+        }
+
+        if let Some(tradable) = self.tradable
+            && item.tradable.unwrap_or(true) != tradable
+        {
+            return false;
+        }
+
+        if let Some(vaulted) = self.vaulted
+            && item.vaulted.unwrap_or(false) != vaulted
+        {
+            return false;
+        }
+
+        if let (Some(max), Some(rank)) = (self.max_mastery_rank, item.mastery_rank)
+            && rank > max
+        {
+            return false;
+        }
+
+        true
+    }
+}
@@ -1,11 +1,11 @@
 use serde::Deserialize;
 
-#[derive(Copy, Clone, Deserialize)]
+#[derive(Copy, Clone, Deserialize, Debug)]
 pub struct APIV1Result<T> {
     pub payload: T,
 }
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Deserialize, Debug)]
 pub struct ApiResult<T> {
     #[serde(rename = "apiVersion")]
     pub api_version: String,
@@ -1,4 +1,5 @@
 use crate::types::user::MinimalUser;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -10,7 +11,30 @@ pub enum OrderType {
     Sell,
 }
 
-#[derive(Clone, Deserialize, Debug)]
+impl OrderType {
+    /// The order type on the other side of a trade, buy for sell and sell for buy
+    pub fn opposite(&self) -> OrderType {
+        match self {
+            OrderType::Buy => OrderType::Sell,
+            OrderType::Sell => OrderType::Buy,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderType::Buy => "buy",
+            OrderType::Sell => "sell",
+        }
+    }
+}
+
+impl std::fmt::Display for OrderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug, Eq, PartialEq)]
 pub struct Order {
     pub id: String,
     #[serde(rename = "type")]
@@ -41,17 +65,19 @@ pub struct Order {
     pub item_id: String, // ID of the item
 
     #[serde(rename = "createdAt")]
-    pub created_at: String, // Timestamp of when the order was created
+    pub created_at: DateTime<Utc>, // Timestamp of when the order was created
     #[serde(rename = "updatedAt")]
-    pub updated_at: String, // Timestamp of when the order was last updated
+    pub updated_at: DateTime<Utc>, // Timestamp of when the order was last updated
 }
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
 pub struct Item {
     pub id: String,
     #[serde(default = "Vec::new")]
     pub tags: Vec<String>,
     pub slug: String,
+    #[serde(rename = "gameRef", skip_serializing_if = "Option::is_none")]
+    pub game_ref: Option<String>, // Internal unique name used by the game (e.g. /Lotus/...)
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tradable: Option<bool>,
@@ -80,11 +106,13 @@ pub struct Item {
 
     #[serde(rename = "reqMasteryRank", skip_serializing_if = "Option::is_none")]
     pub mastery_rank: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtypes: Option<Vec<String>>, // Valid order subtypes (e.g. Kuva weapons, Ephemeras), if applicable
     #[serde(default = "HashMap::new")]
     pub i18n: HashMap<String, ItemTranslation>,
 }
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, Debug, Eq, PartialEq)]
 pub struct ItemTranslation {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -129,3 +157,65 @@ pub struct OrdersTopResult {
     pub buy: Vec<OrderWithUser>,
     pub sell: Vec<OrderWithUser>,
 }
+
+#[derive(Clone, Copy, Deserialize, Debug, Eq, PartialEq)]
+pub enum RelicRarity {
+    #[serde(rename = "common")]
+    Common,
+    #[serde(rename = "uncommon")]
+    Uncommon,
+    #[serde(rename = "rare")]
+    Rare,
+}
+
+impl RelicRarity {
+    /// Chance of an intact relic dropping a single reward of this rarity
+    pub fn intact_chance(&self) -> f32 {
+        match self {
+            RelicRarity::Common => 0.2533,
+            RelicRarity::Uncommon => 0.11,
+            RelicRarity::Rare => 0.02,
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct RelicReward {
+    #[serde(rename = "itemSlug", alias = "slug")]
+    pub item_slug: String,
+    pub rarity: RelicRarity,
+}
+
+/**
+A void relic, see [`get_relic`][crate::Client::get_relic]
+
+WFM does not publish relic reward tables, `rewards` is left empty by the client and has to be
+filled from another source (e.g. the official drop tables) before using `expected_ducats`
+*/
+#[derive(Clone, Debug)]
+pub struct Relic {
+    pub id: String,
+    pub slug: String,
+    pub rewards: Vec<RelicReward>,
+}
+
+impl Relic {
+    /**
+    Calculate the average amount of ducats gained from opening an intact relic
+
+    # Arguments
+    - `ducats`: Looks up the ducat value of a reward by its slug, e.g.
+      `|slug| client.find_by_slug(slug).and_then(|item| item.get_type().ducats)`
+
+    # Returns
+    The expected ducats, rewards without a ducat value count as 0
+    */
+    pub fn expected_ducats(&self, ducats: impl Fn(&str) -> Option<u32>) -> f32 {
+        self.rewards
+            .iter()
+            .map(|reward| {
+                ducats(&reward.item_slug).unwrap_or_default() as f32 * reward.rarity.intact_chance()
+            })
+            .sum()
+    }
+}
@@ -3,10 +3,12 @@
 Mostly used for communicating between the API but contains some other structures used throughout the library
 */
 
+pub mod auction;
 pub mod filter;
 pub mod http;
 pub mod item;
 pub mod request;
 pub mod riven;
+pub mod statistics;
 pub mod transaction;
 pub mod user;
@@ -1,8 +1,11 @@
-use serde::Serialize;
+use serde::{Serialize, Serializer};
+use serde_json::json;
 
+use crate::types::auction::RivenAttributeValue;
 use crate::types::item::OrderType;
+use crate::types::riven::Polarity;
 
-#[derive(Serialize, Default)]
+#[derive(Serialize, Default, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderUpdateParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -17,6 +20,50 @@ pub struct OrderUpdateParams {
     pub visible: Option<bool>,
 }
 
+impl OrderUpdateParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_platinum(mut self, platinum: u32) -> Self {
+        self.platinum = Some(platinum);
+        self
+    }
+    pub fn with_quantity(mut self, quantity: u32) -> Self {
+        self.quantity = Some(quantity);
+        self
+    }
+    pub fn with_per_trade(mut self, per_trade: u32) -> Self {
+        self.per_trade = Some(per_trade);
+        self
+    }
+    pub fn with_rank(mut self, rank: u32) -> Self {
+        self.rank = Some(rank);
+        self
+    }
+    pub fn with_visible(mut self, visible: bool) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// Whether no changes are set, WFM rejects such updates
+    pub fn is_empty(&self) -> bool {
+        self.platinum.is_none()
+            && self.quantity.is_none()
+            && self.per_trade.is_none()
+            && self.rank.is_none()
+            && self.visible.is_none()
+    }
+}
+
+/// Variant of an item to create an order for, see [`Item::order_request`][crate::client::Item::order_request]
+#[derive(Default, Debug, Clone)]
+pub struct OrderVariant {
+    pub rank: Option<u8>,
+    pub charges: Option<u8>,
+    pub subtype: Option<String>,
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderCreationRequest {
@@ -92,3 +139,98 @@ impl OrderCreationRequest {
         self
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct RivenAuctionRequest {
+    pub weapon_slug: String,
+    pub name: String, // Rolled riven name, e.g. "visi-ritacron"
+    pub attributes: Vec<RivenAttributeValue>,
+    pub mastery_rank: u8,
+    pub re_rolls: u32,
+    pub polarity: Polarity,
+    pub mod_rank: u8,
+
+    pub starting_price: u32,
+    pub buyout_price: Option<u32>, // No buyout when None
+    pub minimal_reputation: i32,
+    pub note: String,
+    pub private: bool,
+}
+
+impl RivenAuctionRequest {
+    pub fn new(
+        weapon_slug: &str,
+        name: &str,
+        attributes: Vec<RivenAttributeValue>,
+        polarity: Polarity,
+        starting_price: u32,
+    ) -> Self {
+        RivenAuctionRequest {
+            weapon_slug: weapon_slug.to_string(),
+            name: name.to_string(),
+            attributes,
+            mastery_rank: 8,
+            re_rolls: 0,
+            polarity,
+            mod_rank: 0,
+            starting_price,
+            buyout_price: None,
+            minimal_reputation: 0,
+            note: String::new(),
+            private: false,
+        }
+    }
+
+    pub fn with_mastery_rank(mut self, mastery_rank: u8) -> Self {
+        self.mastery_rank = mastery_rank;
+        self
+    }
+    pub fn with_re_rolls(mut self, re_rolls: u32) -> Self {
+        self.re_rolls = re_rolls;
+        self
+    }
+    pub fn with_mod_rank(mut self, mod_rank: u8) -> Self {
+        self.mod_rank = mod_rank;
+        self
+    }
+    pub fn with_buyout_price(mut self, buyout_price: u32) -> Self {
+        self.buyout_price = Some(buyout_price);
+        self
+    }
+    pub fn with_minimal_reputation(mut self, minimal_reputation: i32) -> Self {
+        self.minimal_reputation = minimal_reputation;
+        self
+    }
+    pub fn with_note(mut self, note: &str) -> Self {
+        self.note = note.to_string();
+        self
+    }
+    pub fn with_private(mut self, private: bool) -> Self {
+        self.private = private;
+        self
+    }
+}
+
+// The v1 auction endpoint expects the riven itself nested under "item"
+impl Serialize for RivenAuctionRequest {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        json!({
+            "starting_price": self.starting_price,
+            "buyout_price": self.buyout_price,
+            "minimal_reputation": self.minimal_reputation,
+            "note": self.note,
+            "private": self.private,
+            "item": {
+                "type": "riven",
+                "weapon_url_name": self.weapon_slug,
+                "name": self.name,
+                "attributes": self.attributes,
+                "mastery_level": self.mastery_rank,
+                "re_rolls": self.re_rolls,
+                "polarity": self.polarity,
+                "mod_rank": self.mod_rank,
+            }
+        })
+        .serialize(serializer)
+    }
+}
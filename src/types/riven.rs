@@ -1,7 +1,7 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Clone, Copy, Deserialize, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, PartialEq)]
 pub enum RivenType {
     #[serde(rename = "kitgun")]
     Kitgun,
@@ -17,7 +17,19 @@ pub enum RivenType {
     Zaw,
 }
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, Eq, PartialEq)]
+pub enum Polarity {
+    #[serde(rename = "madurai")]
+    Madurai,
+    #[serde(rename = "vazarin")]
+    Vazarin,
+    #[serde(rename = "naramon")]
+    Naramon,
+    #[serde(rename = "zenurik")]
+    Zenurik,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct Riven {
     pub id: String,
     pub slug: String,
@@ -38,9 +50,8 @@ pub struct Riven {
     pub i18n: HashMap<String, RivenTranslation>,
 }
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct RivenTranslation {
-    #[serde(skip_serializing_if = "Option::is_none")]
     pub name: String,
 
     #[serde(rename = "wikiLink", skip_serializing_if = "Option::is_none")]
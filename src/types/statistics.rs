@@ -0,0 +1,33 @@
+use serde::Deserialize;
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct ItemStatistics {
+    #[serde(rename = "statistics_closed")]
+    pub closed: StatisticsPeriods,
+    #[serde(rename = "statistics_live")]
+    pub live: StatisticsPeriods,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct StatisticsPeriods {
+    #[serde(rename = "48hours", default = "Vec::new")]
+    pub last_48_hours: Vec<StatisticsEntry>,
+    #[serde(rename = "90days", default = "Vec::new")]
+    pub last_90_days: Vec<StatisticsEntry>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct StatisticsEntry {
+    pub datetime: String,
+    pub volume: u32, // Amount of items traded (closed) or listed (live) in the period
+    pub min_price: f64,
+    pub max_price: f64,
+    pub avg_price: f64,
+    pub median: f64,
+
+    pub wa_price: Option<f64>, // Volume weighted average price
+    pub moving_avg: Option<f64>,
+
+    pub order_type: Option<String>, // Only present on live statistics
+    pub mod_rank: Option<u32>,
+}
@@ -1,27 +1,21 @@
+pub use crate::types::item::OrderType;
 use crate::types::user::MinimalUser;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Clone, Copy, Deserialize, Debug, Eq, PartialEq)]
-pub enum OrderType {
-    #[serde(rename = "buy")]
-    Buy,
-    #[serde(rename = "sell")]
-    Sell,
-}
-
 #[derive(Clone, Deserialize, Debug)]
 pub struct Transaction {
     pub id: String,
     #[serde(rename = "type")]
-    pub order_type: String,
+    pub order_type: OrderType,
     #[serde(rename = "originId")]
     pub origin_id: String,
     pub platinum: i32,
     pub quantity: i32,
     #[serde(rename = "createdAt")]
-    pub created_at: String,
+    pub created_at: DateTime<Utc>,
     #[serde(rename = "updatedAt")]
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
     pub item: TransactionItem,
 }
 #[derive(Clone, Serialize, Deserialize, Debug)]
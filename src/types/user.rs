@@ -1,20 +1,23 @@
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Deserialize, Eq, PartialEq, Debug)]
+#[derive(Clone, Serialize, Deserialize, Eq, PartialEq, Debug)]
 pub enum StatusType {
     #[serde(rename = "offline")]
     Offline,
     #[serde(rename = "online")]
     Online,
-    #[serde(rename = "in_game", alias = "ingame")]
+    #[serde(rename(serialize = "ingame", deserialize = "in_game"), alias = "ingame")]
     InGame,
+    #[serde(rename = "invisible")]
+    Invisible,
 }
 
 fn default_status_type() -> StatusType {
     StatusType::Offline
 }
 
-#[derive(Clone, Deserialize)]
+#[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct FullUser {
     pub id: String,
     #[serde(rename = "ingame_name", alias = "ingameName")]
@@ -29,9 +32,21 @@ pub struct FullUser {
     
     #[serde(alias = "unreadNotifications")]
     pub unread_messages: i32,
+
+    /// Path of the users avatar, relative to the static assets URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// Whether the user trades with players on other platforms
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crossplay: Option<bool>,
+    /// Whether the account has been verified
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<bool>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Clone, Deserialize, Debug)]
 pub struct MinimalUser {
     pub id: String,
     #[serde(rename = "ingame_name", alias = "ingameName")]
@@ -39,4 +54,26 @@ pub struct MinimalUser {
     pub reputation: i32,
     #[serde(rename = "status", default = "default_status_type")]
     pub status_type: StatusType,
-}
\ No newline at end of file
+    #[serde(rename = "lastSeen", default)]
+    pub last_seen: Option<DateTime<Utc>>,
+    /// The platform the user plays on (e.g. `pc`, `ps4`), not always included
+    #[serde(default)]
+    pub platform: Option<String>,
+    /// Whether the user trades with players on other platforms
+    #[serde(default)]
+    pub crossplay: Option<bool>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+pub struct PublicUser {
+    pub id: String,
+    #[serde(rename = "ingame_name", alias = "ingameName")]
+    pub name: String,
+    pub slug: Option<String>,
+    pub reputation: i32,
+    pub platform: String,
+    #[serde(rename = "status", default = "default_status_type")]
+    pub status_type: StatusType,
+    #[serde(rename = "lastSeen", default)]
+    pub last_seen: Option<DateTime<Utc>>,
+}